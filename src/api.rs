@@ -33,8 +33,7 @@
 //!
 //! // Create the client.
 //! let auth = Auth::Token("API_KEY".into());
-//! let req_client = reqwest::Client::new();
-//! let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+//! let client = Cdg::builder().auth(auth).format(Format::Json).build().unwrap();
 //!
 //! // Create a simple endpoint. This one gets recent Bills from the 118th Congress.
 //! let endpoint = bill::Congress::builder().congress(118_u8).build().unwrap();
@@ -45,11 +44,21 @@
 //! # })
 //! ```
 
+mod batch;
+#[cfg(feature = "blocking")]
+mod blocking;
+mod cancel;
 mod client;
 mod endpoint;
 mod error;
+mod formatted;
+mod link;
+mod paged;
 mod params;
 pub(crate) mod query;
+mod response;
+mod route;
+mod timeout;
 
 pub mod common;
 
@@ -74,13 +83,51 @@ pub mod senate_communication;
 pub mod summaries;
 pub mod treaty;
 
+pub use self::batch::Batch;
+pub use self::batch::batch;
+
+#[cfg(feature = "blocking")]
+pub use self::blocking::BlockingClient;
+#[cfg(feature = "blocking")]
+pub use self::blocking::BlockingQuery;
+pub use self::cancel::CancellationToken;
 pub use self::client::Client;
 
 pub use self::endpoint::Endpoint;
 pub use self::endpoint::UrlBase;
 pub use self::error::ApiError;
+pub use self::error::ErrorResponse;
+pub(crate) use self::error::REDACTED;
+#[cfg(any(feature = "reqwest", feature = "hyper", feature = "ureq"))]
+pub(crate) use self::error::redact_str;
+#[cfg(feature = "reqwest")]
+pub(crate) use self::error::redact_url;
+
+pub use self::formatted::Formatted;
+pub use self::formatted::formatted;
+
+pub use self::link::Link;
+pub use self::link::LinkError;
+pub use self::link::ListLink;
+
+pub use self::paged::Page;
+pub use self::paged::Pageable;
+pub use self::paged::Paged;
+pub use self::paged::Pagination;
+pub use self::paged::PaginationInfo;
+pub use self::paged::paged;
 
 pub use self::params::ParamValue;
 pub use self::params::QueryParams;
 
 pub use self::query::Query;
+pub use self::query::RawQuery;
+
+pub use self::response::FromResponse;
+pub use self::response::Response;
+
+pub use self::route::Route;
+pub use self::route::RouteError;
+
+pub use self::timeout::Timeout;
+pub use self::timeout::timeout;
@@ -36,7 +36,7 @@ pub enum CongressionalAmendmentType {
 }
 
 impl CongressionalAmendmentType {
-    fn as_str(self) -> &'static str {
+    pub(crate) fn as_str(self) -> &'static str {
         match self {
             CongressionalAmendmentType::Hamdt => "hamdt",
             CongressionalAmendmentType::Samdt => "samdt",
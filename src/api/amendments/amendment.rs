@@ -1,9 +1,12 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use chrono::{DateTime, Utc};
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::params::QueryParams};
+use crate::{api::endpoint::Endpoint, api::paged::Pageable, api::params::QueryParams};
 
 /// Represents the /amendment endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
@@ -23,6 +26,16 @@ impl Amendment {
     pub fn builder() -> AmendmentBuilder {
         AmendmentBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> AmendmentBuilder {
+        AmendmentBuilder {
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+            from_date_time: Some(self.from_date_time),
+            to_date_time: Some(self.to_date_time),
+        }
+    }
 }
 
 impl Endpoint for Amendment {
@@ -34,21 +47,23 @@ impl Endpoint for Amendment {
         "amendment".into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
         params.push_opt("limit", self.limit);
-        params.push_opt("from_date_time", self.from_date_time);
-        params.push_opt("to_date_time", self.to_date_time);
+        params.push_opt("fromDateTime", self.from_date_time);
+        params.push_opt("toDateTime", self.to_date_time);
 
         params
     }
 }
 
+impl Pageable for Amendment {}
+
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::paged::Page, api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -59,14 +74,28 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
-
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let client = Cassette::new("amendments/amendment/endpoint");
 
         let endpoint = Amendment::builder().build().unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
+
+        assert!(!page.items().is_empty());
+    }
+
+    #[test]
+    fn endpoint_url() {
+        let endpoint = Amendment::builder()
+            .offset(20)
+            .limit(10)
+            .from_date_time("2024-01-01T00:00:00Z".parse().unwrap())
+            .to_date_time("2024-12-31T00:00:00Z".parse().unwrap())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "amendment?offset=20&limit=10&fromDateTime=2024-01-01T00%3A00%3A00Z&toDateTime=2024-12-31T00%3A00%3A00Z"
+        );
     }
 }
@@ -1,17 +1,20 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::params::QueryParams};
-
-use super::CongressionalAmendmentType;
+use crate::{
+    api::amendments::CongressionalAmendmentType, api::endpoint::Endpoint, api::params::QueryParams,
+};
 
 /// Represents the /amendment/:congress/:amendmentType/:amendmentNumber endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct AmendmentNumber {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     amendment_type: CongressionalAmendmentType,
     #[builder(setter(into))]
@@ -22,6 +25,15 @@ impl AmendmentNumber {
     pub fn builder() -> AmendmentNumberBuilder {
         AmendmentNumberBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> AmendmentNumberBuilder {
+        AmendmentNumberBuilder {
+            congress: Some(self.congress),
+            amendment_type: Some(self.amendment_type),
+            amendment_number: Some(self.amendment_number),
+        }
+    }
 }
 
 impl Endpoint for AmendmentNumber {
@@ -39,7 +51,7 @@ impl Endpoint for AmendmentNumber {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         QueryParams::default()
     }
 }
@@ -47,11 +59,7 @@ impl Endpoint for AmendmentNumber {
 #[cfg(test)]
 mod tests {
 
-    use crate::{
-        api::{common::Format, query::Query},
-        auth::Auth,
-        cdg::Cdg,
-    };
+    use crate::{api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -67,12 +75,22 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("amendments/amendment_number/endpoint");
+
+        let endpoint = AmendmentNumber::builder()
+            .congress(117_u8)
+            .amendment_type(CongressionalAmendmentType::Samdt)
+            .amendment_number(2137_u32)
+            .build()
+            .unwrap();
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
 
+        assert!(res["amendment"].is_object());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = AmendmentNumber::builder()
             .congress(117_u8)
             .amendment_type(CongressionalAmendmentType::Samdt)
@@ -80,6 +98,9 @@ mod tests {
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "amendment/117/samdt/2137"
+        );
     }
 }
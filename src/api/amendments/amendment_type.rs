@@ -1,18 +1,22 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use chrono::{DateTime, Utc};
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::params::QueryParams};
-
-use super::CongressionalAmendmentType;
+use crate::{
+    api::amendments::CongressionalAmendmentType, api::endpoint::Endpoint, api::paged::Pageable,
+    api::params::QueryParams,
+};
 
 /// Represents the /amendment/:congress/:amendmentType endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct AmendmentType {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     amendment_type: CongressionalAmendmentType,
     #[builder(default)]
@@ -29,6 +33,18 @@ impl AmendmentType {
     pub fn builder() -> AmendmentTypeBuilder {
         AmendmentTypeBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> AmendmentTypeBuilder {
+        AmendmentTypeBuilder {
+            congress: Some(self.congress),
+            amendment_type: Some(self.amendment_type),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+            from_date_time: Some(self.from_date_time),
+            to_date_time: Some(self.to_date_time),
+        }
+    }
 }
 
 impl Endpoint for AmendmentType {
@@ -45,21 +61,23 @@ impl Endpoint for AmendmentType {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
         params.push_opt("limit", self.limit);
-        params.push_opt("from_date_time", self.from_date_time);
-        params.push_opt("to_date_time", self.to_date_time);
+        params.push_opt("fromDateTime", self.from_date_time);
+        params.push_opt("toDateTime", self.to_date_time);
 
         params
     }
 }
 
+impl Pageable for AmendmentType {}
+
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::paged::Page, api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -74,18 +92,34 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("amendments/amendment_type/endpoint");
+
+        let endpoint = AmendmentType::builder()
+            .congress(117_u8)
+            .amendment_type(CongressionalAmendmentType::Suamdt)
+            .build()
+            .unwrap();
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
 
+        assert!(!page.items().is_empty());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = AmendmentType::builder()
             .congress(117_u8)
             .amendment_type(CongressionalAmendmentType::Suamdt)
+            .offset(20)
+            .limit(10)
+            .from_date_time("2024-01-01T00:00:00Z".parse().unwrap())
+            .to_date_time("2024-12-31T00:00:00Z".parse().unwrap())
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "amendment/117/suamdt?offset=20&limit=10&fromDateTime=2024-01-01T00%3A00%3A00Z&toDateTime=2024-12-31T00%3A00%3A00Z"
+        );
     }
 }
@@ -1,17 +1,21 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::params::QueryParams};
-
-use super::CongressionalAmendmentType;
+use crate::{
+    api::amendments::CongressionalAmendmentType, api::endpoint::Endpoint, api::paged::Pageable,
+    api::params::QueryParams,
+};
 
 /// Represents the /amendment/:congress/:amendmentType/:amendmentNumber/text endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct Text {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     amendment_type: CongressionalAmendmentType,
     #[builder(setter(into))]
@@ -26,6 +30,17 @@ impl Text {
     pub fn builder() -> TextBuilder {
         TextBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> TextBuilder {
+        TextBuilder {
+            congress: Some(self.congress),
+            amendment_type: Some(self.amendment_type),
+            amendment_number: Some(self.amendment_number),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+        }
+    }
 }
 
 impl Endpoint for Text {
@@ -43,7 +58,7 @@ impl Endpoint for Text {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
@@ -53,9 +68,11 @@ impl Endpoint for Text {
     }
 }
 
+impl Pageable for Text {}
+
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::paged::Page, api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -71,19 +88,34 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("amendments/text/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = Text::builder()
+            .congress(117_u8)
+            .amendment_type(CongressionalAmendmentType::Samdt)
+            .amendment_number(2137_u32)
+            .build()
+            .unwrap();
+
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
 
+        assert!(!page.items().is_empty());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = Text::builder()
             .congress(117_u8)
             .amendment_type(CongressionalAmendmentType::Samdt)
             .amendment_number(2137_u32)
+            .offset(20)
+            .limit(10)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "amendment/117/samdt/2137/text?offset=20&limit=10"
+        );
     }
 }
@@ -0,0 +1,233 @@
+//! Concurrent execution of many endpoints.
+//!
+//! Datasets are often built by querying thousands of detail endpoints, such as
+//! [`bill::BillNumber`](crate::api::bill::BillNumber). The [`Batch`] wrapper runs them against a
+//! client with bounded concurrency, returning a result per endpoint so that a single failure does
+//! not abort the whole batch.
+
+use futures_util::stream::{self, Stream, StreamExt};
+
+use crate::api::{
+    cancel::{CancellationToken, cancellable},
+    client::Client,
+    endpoint::Endpoint,
+    error::ApiError,
+    query::Query,
+};
+
+/// Runs a set of endpoints against a client with bounded concurrency.
+#[derive(Debug, Clone)]
+pub struct Batch<E> {
+    endpoints: Vec<E>,
+    concurrency: usize,
+    cancellation: Option<CancellationToken>,
+}
+
+/// Run the endpoints with at most `concurrency` requests in flight.
+pub fn batch<I>(endpoints: I, concurrency: usize) -> Batch<I::Item>
+where
+    I: IntoIterator,
+{
+    Batch {
+        endpoints: endpoints.into_iter().collect(),
+        concurrency: concurrency.max(1),
+        cancellation: None,
+    }
+}
+
+impl<E> Batch<E> {
+    /// The endpoints in the batch, in input order.
+    pub fn endpoints(&self) -> &[E] {
+        &self.endpoints
+    }
+
+    /// The maximum number of requests in flight.
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Stop querying the endpoints once the token is cancelled.
+    ///
+    /// Requests in flight are dropped, and they and the endpoints not yet
+    /// queried fail with [`ApiError::Cancelled`].
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+}
+
+impl<E> Batch<E>
+where
+    E: Endpoint,
+{
+    /// Stream the result for each endpoint in input order.
+    ///
+    /// A slow request holds back the results after it, although up to `concurrency` requests
+    /// keep running in the meantime.
+    pub fn stream<'a, T, C>(
+        &'a self,
+        client: &'a C,
+    ) -> impl Stream<Item = Result<T, ApiError<C::Error>>> + 'a
+    where
+        E: Query<T, C>,
+        T: 'a,
+        C: Client,
+    {
+        stream::iter(&self.endpoints)
+            .map(move |endpoint| self.query_one(endpoint, client))
+            .buffered(self.concurrency)
+    }
+
+    /// Stream the result for each endpoint as soon as it completes, along with the index of the
+    /// endpoint in the batch.
+    pub fn stream_unordered<'a, T, C>(
+        &'a self,
+        client: &'a C,
+    ) -> impl Stream<Item = (usize, Result<T, ApiError<C::Error>>)> + 'a
+    where
+        E: Query<T, C>,
+        T: 'a,
+        C: Client,
+    {
+        stream::iter(self.endpoints.iter().enumerate())
+            .map(move |(index, endpoint)| async move {
+                (index, self.query_one(endpoint, client).await)
+            })
+            .buffer_unordered(self.concurrency)
+    }
+
+    /// Run every endpoint, returning their results in input order.
+    pub async fn query<T, C>(&self, client: &C) -> Vec<Result<T, ApiError<C::Error>>>
+    where
+        E: Query<T, C>,
+        C: Client,
+    {
+        self.stream(client).collect().await
+    }
+
+    async fn query_one<T, C>(&self, endpoint: &E, client: &C) -> Result<T, ApiError<C::Error>>
+    where
+        E: Query<T, C>,
+        C: Client,
+    {
+        let query = Query::<T, C>::query(endpoint, client);
+        cancellable(self.cancellation.as_ref(), &endpoint.endpoint(), query).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use http::StatusCode;
+    use tokio::time::Instant;
+
+    use crate::{
+        api::{ApiError, bill, common::BillType},
+        test_util::{MockClient, MockResponse},
+    };
+
+    use super::*;
+
+    /// Answers `bill/117/hr/:number` after `number` milliseconds, failing for odd numbers.
+    fn client(numbers: &[u32]) -> MockClient {
+        let client = MockClient::new();
+        for &number in numbers {
+            let status = if number.is_multiple_of(2) {
+                StatusCode::OK
+            } else {
+                StatusCode::NOT_FOUND
+            };
+            client.expect(
+                MockResponse::builder()
+                    .endpoint(format!("bill/117/hr/{number}"))
+                    .status(status)
+                    .body(number)
+                    .delay(Duration::from_millis(number.into()))
+                    .build()
+                    .unwrap(),
+            );
+        }
+        client
+    }
+
+    fn endpoints(numbers: &[u32]) -> Vec<bill::BillNumber> {
+        numbers
+            .iter()
+            .map(|&number| {
+                bill::BillNumber::builder()
+                    .congress(117_u8)
+                    .bill_type(BillType::Hr)
+                    .bill_number(number)
+                    .build()
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn preserves_input_order() {
+        let numbers = [40, 3, 20, 10];
+        let client = client(&numbers);
+        let batch = batch(endpoints(&numbers), 2);
+
+        let results: Vec<Result<u64, _>> = batch.query(&client).await;
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(*results[0].as_ref().unwrap(), 40);
+        assert!(matches!(results[1], Err(ApiError::NotFound { .. })));
+        assert_eq!(*results[2].as_ref().unwrap(), 20);
+        assert_eq!(*results[3].as_ref().unwrap(), 10);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn limits_concurrency() {
+        let numbers = [10, 12, 14, 16, 18, 20];
+        let client = client(&numbers);
+        let batch = batch(endpoints(&numbers), 2);
+
+        let start = Instant::now();
+        let results: Vec<Result<u64, _>> = batch.query(&client).await;
+
+        assert!(results.iter().all(Result::is_ok));
+        // Two at a time: 10 + 14 + 18 on one slot, 12 + 16 + 20 on the other.
+        assert_eq!(start.elapsed(), Duration::from_millis(48));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn streams_completion_order() {
+        let numbers = [60, 2, 30];
+        let client = client(&numbers);
+        let batch = batch(endpoints(&numbers), 3);
+
+        let order: Vec<usize> = batch
+            .stream_unordered::<u64, _>(&client)
+            .map(|(index, _)| index)
+            .collect()
+            .await;
+
+        assert_eq!(order, [1, 2, 0]);
+        assert_eq!(client.calls().len(), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cancellation() {
+        let numbers = [4, 200, 2];
+        let client = client(&numbers);
+        let token = CancellationToken::new();
+        let batch = batch(endpoints(&numbers), 1).with_cancellation(token.clone());
+
+        let canceller = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            canceller.cancel();
+        });
+        let results: Vec<Result<u64, _>> = batch.query(&client).await;
+
+        assert_eq!(*results[0].as_ref().unwrap(), 4);
+        assert!(
+            matches!(&results[1], Err(ApiError::Cancelled { endpoint }) if endpoint == "bill/117/hr/200")
+        );
+        assert!(matches!(results[2], Err(ApiError::Cancelled { .. })));
+    }
+}
@@ -1,17 +1,20 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::params::QueryParams};
+use crate::{api::common::BillType, api::endpoint::Endpoint, api::params::QueryParams};
 
-/// Represents the /bill/:congress/:billtype/:billnumber endpoint.
+/// Represents the /bill/:congress/:billType/:billNumber endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct BillNumber {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
-    bill_type: crate::api::common::BillType,
+    bill_type: BillType,
     #[builder(setter(into))]
     bill_number: u32,
 }
@@ -20,6 +23,15 @@ impl BillNumber {
     pub fn builder() -> BillNumberBuilder {
         BillNumberBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> BillNumberBuilder {
+        BillNumberBuilder {
+            congress: Some(self.congress),
+            bill_type: Some(self.bill_type),
+            bill_number: Some(self.bill_number),
+        }
+    }
 }
 
 impl Endpoint for BillNumber {
@@ -37,14 +49,14 @@ impl Endpoint for BillNumber {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         QueryParams::default()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -60,12 +72,22 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("bill/bill_number/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = BillNumber::builder()
+            .congress(117_u8)
+            .bill_type(crate::api::common::BillType::Hr)
+            .bill_number(3076_u32)
+            .build()
+            .unwrap();
+
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
 
+        assert!(res["bill"].is_object());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = BillNumber::builder()
             .congress(117_u8)
             .bill_type(crate::api::common::BillType::Hr)
@@ -73,6 +95,6 @@ mod tests {
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(crate::test::endpoint_url(&endpoint), "bill/117/hr/3076");
     }
 }
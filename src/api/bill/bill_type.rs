@@ -1,16 +1,21 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use chrono::{DateTime, Utc};
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::common::Sort, api::endpoint::Endpoint, api::params::QueryParams};
+use crate::{
+    api::common::Sort, api::endpoint::Endpoint, api::paged::Pageable, api::params::QueryParams,
+};
 
-/// Represents the /bill/:congress/:billtype endpoint.
+/// Represents the /bill/:congress/:billType endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct BillType {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     bill_type: crate::api::common::BillType,
     #[builder(default)]
@@ -29,6 +34,19 @@ impl BillType {
     pub fn builder() -> BillTypeBuilder {
         BillTypeBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> BillTypeBuilder {
+        BillTypeBuilder {
+            congress: Some(self.congress),
+            bill_type: Some(self.bill_type),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+            from_date_time: Some(self.from_date_time),
+            to_date_time: Some(self.to_date_time),
+            sort: Some(self.sort),
+        }
+    }
 }
 
 impl Endpoint for BillType {
@@ -40,22 +58,24 @@ impl Endpoint for BillType {
         format!("bill/{}/{}", self.congress, self.bill_type.as_str()).into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
         params.push_opt("limit", self.limit);
-        params.push_opt("from_date_time", self.from_date_time);
-        params.push_opt("to_date_time", self.to_date_time);
+        params.push_opt("fromDateTime", self.from_date_time);
+        params.push_opt("toDateTime", self.to_date_time);
         params.push_opt("sort", self.sort);
 
         params
     }
 }
 
+impl Pageable for BillType {}
+
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::paged::Page, api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -70,18 +90,35 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("bill/bill_type/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = BillType::builder()
+            .congress(117_u8)
+            .bill_type(crate::api::common::BillType::Hr)
+            .build()
+            .unwrap();
+
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
 
+        assert!(!page.items().is_empty());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = BillType::builder()
             .congress(117_u8)
             .bill_type(crate::api::common::BillType::Hr)
+            .offset(20)
+            .limit(10)
+            .from_date_time("2024-01-01T00:00:00Z".parse().unwrap())
+            .to_date_time("2024-12-31T00:00:00Z".parse().unwrap())
+            .sort(Sort::Desc)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "bill/117/hr?offset=20&limit=10&fromDateTime=2024-01-01T00%3A00%3A00Z&toDateTime=2024-12-31T00%3A00%3A00Z&sort=updateDate+desc"
+        );
     }
 }
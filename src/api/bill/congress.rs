@@ -1,16 +1,21 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use chrono::{DateTime, Utc};
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::common::Sort, api::endpoint::Endpoint, api::params::QueryParams};
+use crate::{
+    api::common::Sort, api::endpoint::Endpoint, api::paged::Pageable, api::params::QueryParams,
+};
 
 /// Represents the /bill/:congress endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct Congress {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(default)]
     offset: Option<u32>,
     #[builder(default)]
@@ -27,6 +32,18 @@ impl Congress {
     pub fn builder() -> CongressBuilder {
         CongressBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> CongressBuilder {
+        CongressBuilder {
+            congress: Some(self.congress),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+            from_date_time: Some(self.from_date_time),
+            to_date_time: Some(self.to_date_time),
+            sort: Some(self.sort),
+        }
+    }
 }
 
 impl Endpoint for Congress {
@@ -38,22 +55,24 @@ impl Endpoint for Congress {
         format!("bill/{}", self.congress).into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
         params.push_opt("limit", self.limit);
-        params.push_opt("from_date_time", self.from_date_time);
-        params.push_opt("to_date_time", self.to_date_time);
+        params.push_opt("fromDateTime", self.from_date_time);
+        params.push_opt("toDateTime", self.to_date_time);
         params.push_opt("sort", self.sort);
 
         params
     }
 }
 
+impl Pageable for Congress {}
+
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::paged::Page, api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -64,14 +83,30 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
-
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let client = Cassette::new("bill/congress/endpoint");
 
         let endpoint = Congress::builder().congress(117_u8).build().unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
+
+        assert!(!page.items().is_empty());
+    }
+
+    #[test]
+    fn endpoint_url() {
+        let endpoint = Congress::builder()
+            .congress(117_u8)
+            .offset(20)
+            .limit(10)
+            .from_date_time("2024-01-01T00:00:00Z".parse().unwrap())
+            .to_date_time("2024-12-31T00:00:00Z".parse().unwrap())
+            .sort(Sort::Desc)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "bill/117?offset=20&limit=10&fromDateTime=2024-01-01T00%3A00%3A00Z&toDateTime=2024-12-31T00%3A00%3A00Z&sort=updateDate+desc"
+        );
     }
 }
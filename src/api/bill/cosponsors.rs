@@ -1,17 +1,22 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::params::QueryParams};
+use crate::{
+    api::common::BillType, api::endpoint::Endpoint, api::paged::Pageable, api::params::QueryParams,
+};
 
-/// Represents the /bill/:congress/:billtype/:billnumber/cosponsors endpoint.
+/// Represents the /bill/:congress/:billType/:billNumber/cosponsors endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct Cosponsors {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
-    bill_type: crate::api::common::BillType,
+    bill_type: BillType,
     #[builder(setter(into))]
     bill_number: u32,
     #[builder(default)]
@@ -24,6 +29,17 @@ impl Cosponsors {
     pub fn builder() -> CosponsorsBuilder {
         CosponsorsBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> CosponsorsBuilder {
+        CosponsorsBuilder {
+            congress: Some(self.congress),
+            bill_type: Some(self.bill_type),
+            bill_number: Some(self.bill_number),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+        }
+    }
 }
 
 impl Endpoint for Cosponsors {
@@ -41,7 +57,7 @@ impl Endpoint for Cosponsors {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
@@ -51,9 +67,11 @@ impl Endpoint for Cosponsors {
     }
 }
 
+impl Pageable for Cosponsors {}
+
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::paged::Page, api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -69,19 +87,34 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("bill/cosponsors/endpoint");
+
+        let endpoint = Cosponsors::builder()
+            .congress(117_u8)
+            .bill_type(crate::api::common::BillType::Hr)
+            .bill_number(3076_u32)
+            .build()
+            .unwrap();
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
 
+        assert!(!page.items().is_empty());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = Cosponsors::builder()
             .congress(117_u8)
             .bill_type(crate::api::common::BillType::Hr)
             .bill_number(3076_u32)
+            .offset(20)
+            .limit(10)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "bill/117/hr/3076/cosponsors?offset=20&limit=10"
+        );
     }
 }
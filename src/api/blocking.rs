@@ -0,0 +1,43 @@
+//! Synchronous counterparts to the [`Client`] and [`Query`] traits.
+
+use std::future::Future;
+
+use crate::api::{client::Client, error::ApiError, query::Query};
+
+/// A client which performs queries synchronously.
+///
+/// A blocking client wraps an async [`Client`] and drives its requests to
+/// completion, so every endpoint usable with [`Query`] is also usable with
+/// [`BlockingQuery`].
+pub trait BlockingClient {
+    /// The async client used to perform requests.
+    type Client: Client;
+
+    /// The async client used to perform requests.
+    fn client(&self) -> &Self::Client;
+
+    /// Runs a future to completion on the current thread.
+    fn block_on<F>(&self, future: F) -> F::Output
+    where
+        F: Future;
+}
+
+/// A trait which represents a query which may be made synchronously to a
+/// congress.gov client.
+pub trait BlockingQuery<T, C>
+where
+    C: BlockingClient,
+{
+    /// Perform the query against the client, blocking until it completes.
+    fn query_blocking(&self, client: &C) -> Result<T, ApiError<<C::Client as Client>::Error>>;
+}
+
+impl<Q, T, C> BlockingQuery<T, C> for Q
+where
+    Q: Query<T, C::Client>,
+    C: BlockingClient,
+{
+    fn query_blocking(&self, client: &C) -> Result<T, ApiError<<C::Client as Client>::Error>> {
+        client.block_on(self.query(client.client()))
+    }
+}
@@ -1,8 +1,11 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::params::QueryParams};
+use crate::{api::endpoint::Endpoint, api::paged::Pageable, api::params::QueryParams};
 
 /// Represents the /bound-congressional-record endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
@@ -18,6 +21,14 @@ impl BoundCongressionalRecord {
     pub fn builder() -> BoundCongressionalRecordBuilder {
         BoundCongressionalRecordBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> BoundCongressionalRecordBuilder {
+        BoundCongressionalRecordBuilder {
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+        }
+    }
 }
 
 impl Endpoint for BoundCongressionalRecord {
@@ -26,10 +37,10 @@ impl Endpoint for BoundCongressionalRecord {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        "bound-congressional-record".to_string().into()
+        "bound-congressional-record".into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
@@ -39,9 +50,11 @@ impl Endpoint for BoundCongressionalRecord {
     }
 }
 
+impl Pageable for BoundCongressionalRecord {}
+
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::paged::Page, api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -52,14 +65,27 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
-
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let client =
+            Cassette::new("bound_congressional_record/bound_congressional_record/endpoint");
 
         let endpoint = BoundCongressionalRecord::builder().build().unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
+
+        assert!(!page.items().is_empty());
+    }
+
+    #[test]
+    fn endpoint_url() {
+        let endpoint = BoundCongressionalRecord::builder()
+            .offset(20)
+            .limit(10)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "bound-congressional-record?offset=20&limit=10"
+        );
     }
 }
@@ -1,8 +1,11 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::params::QueryParams};
+use crate::{api::endpoint::Endpoint, api::paged::Pageable, api::params::QueryParams};
 
 /// Represents the /bound-congressional-record/:year/:month/:day endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
@@ -24,6 +27,17 @@ impl Day {
     pub fn builder() -> DayBuilder {
         DayBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> DayBuilder {
+        DayBuilder {
+            year: Some(self.year),
+            month: Some(self.month),
+            day: Some(self.day),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+        }
+    }
 }
 
 impl Endpoint for Day {
@@ -39,7 +53,7 @@ impl Endpoint for Day {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
@@ -49,9 +63,11 @@ impl Endpoint for Day {
     }
 }
 
+impl Pageable for Day {}
+
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::paged::Page, api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -67,19 +83,34 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("bound_congressional_record/day/endpoint");
+
+        let endpoint = Day::builder()
+            .year(2020_u16)
+            .month(4_u8)
+            .day(6_u8)
+            .build()
+            .unwrap();
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
 
+        assert!(!page.items().is_empty());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = Day::builder()
             .year(2020_u16)
             .month(4_u8)
             .day(6_u8)
+            .offset(20)
+            .limit(10)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "bound-congressional-record/2020/4/6?offset=20&limit=10"
+        );
     }
 }
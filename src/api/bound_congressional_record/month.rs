@@ -1,8 +1,11 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::params::QueryParams};
+use crate::{api::endpoint::Endpoint, api::paged::Pageable, api::params::QueryParams};
 
 /// Represents the /bound-congressional-record/:year/:month endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
@@ -22,6 +25,16 @@ impl Month {
     pub fn builder() -> MonthBuilder {
         MonthBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> MonthBuilder {
+        MonthBuilder {
+            year: Some(self.year),
+            month: Some(self.month),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+        }
+    }
 }
 
 impl Endpoint for Month {
@@ -33,7 +46,7 @@ impl Endpoint for Month {
         format!("bound-congressional-record/{}/{}", self.year, self.month).into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
@@ -43,9 +56,11 @@ impl Endpoint for Month {
     }
 }
 
+impl Pageable for Month {}
+
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::paged::Page, api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -56,14 +71,28 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
-
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let client = Cassette::new("bound_congressional_record/month/endpoint");
 
         let endpoint = Month::builder().year(1990_u16).month(5_u8).build().unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
+
+        assert!(!page.items().is_empty());
+    }
+
+    #[test]
+    fn endpoint_url() {
+        let endpoint = Month::builder()
+            .year(1990_u16)
+            .month(5_u8)
+            .offset(20)
+            .limit(10)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "bound-congressional-record/1990/5?offset=20&limit=10"
+        );
     }
 }
@@ -0,0 +1,129 @@
+//! Cooperative cancellation of long running queries.
+//!
+//! A [`CancellationToken`] is shared between the code driving a [`Paged`](crate::api::Paged) or
+//! [`Batch`](crate::api::Batch) query and the code deciding to stop it, e.g. a shutdown handler.
+//! Once cancelled, requests in flight are dropped and no further requests are started.
+
+use std::{
+    future::Future,
+    pin::pin,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use futures_util::future::{self, Either};
+use tokio::sync::Notify;
+
+use crate::api::error::ApiError;
+
+/// A token used to cancel queries.
+///
+/// Clones share the same state, so cancelling any clone cancels every query using the token.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    notify: Notify,
+}
+
+impl CancellationToken {
+    /// Create a token which is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the queries using this token.
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        self.inner.notify.notify_waiters();
+    }
+
+    /// Whether the token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Wait until the token is cancelled.
+    pub async fn cancelled(&self) {
+        // Register before checking the flag so that a concurrent `cancel` is not missed.
+        let notified = self.inner.notify.notified();
+        if self.is_cancelled() {
+            return;
+        }
+        notified.await;
+    }
+}
+
+/// Runs a query of the endpoint until it completes or the token, if any, is cancelled.
+pub(crate) async fn cancellable<F, T, E>(
+    token: Option<&CancellationToken>,
+    endpoint: &str,
+    query: F,
+) -> Result<T, ApiError<E>>
+where
+    F: Future<Output = Result<T, ApiError<E>>>,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let Some(token) = token else {
+        return query.await;
+    };
+    let cancelled = || ApiError::Cancelled {
+        endpoint: endpoint.into(),
+    };
+    if token.is_cancelled() {
+        return Err(cancelled());
+    }
+
+    match future::select(pin!(query), pin!(token.cancelled())).await {
+        Either::Left((res, _)) => res,
+        Either::Right(_) => Err(cancelled()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::test_util::MockError;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn cancels_pending_query() {
+        let token = CancellationToken::new();
+        let canceller = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            canceller.cancel();
+        });
+
+        let res = cancellable(
+            Some(&token),
+            "bill",
+            future::pending::<Result<(), ApiError<MockError>>>(),
+        )
+        .await;
+
+        assert!(token.is_cancelled());
+        assert!(matches!(res, Err(ApiError::Cancelled { endpoint }) if endpoint == "bill"));
+    }
+
+    #[tokio::test]
+    async fn does_not_start_after_cancel() {
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let res = cancellable(Some(&token), "bill", async {
+            Ok::<_, ApiError<MockError>>(())
+        })
+        .await;
+
+        assert!(matches!(res, Err(ApiError::Cancelled { .. })));
+    }
+}
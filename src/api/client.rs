@@ -2,10 +2,10 @@
 
 use bytes::Bytes;
 use http::Response;
-use std::error::Error;
+use std::{error::Error, time::Duration};
 use url::Url;
 
-use crate::api::error::ApiError;
+use crate::api::{common::Format, error::ApiError};
 
 pub trait Client {
     type Error: Error + Send + Sync + 'static;
@@ -14,6 +14,16 @@ pub trait Client {
 
     fn set_auth(&self, url: &mut Url);
 
+    /// The response format requested when a query does not specify one.
+    fn format(&self) -> Format {
+        Format::Json
+    }
+
+    /// The maximum duration of a query which does not set its own timeout.
+    fn timeout(&self) -> Option<Duration> {
+        None
+    }
+
     fn rest(
         &self,
         request: http::request::Builder,
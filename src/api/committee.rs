@@ -43,7 +43,7 @@ pub enum CommitteeChamber {
 }
 
 impl CommitteeChamber {
-    fn as_str(self) -> &'static str {
+    pub(crate) fn as_str(self) -> &'static str {
         match self {
             CommitteeChamber::House => "house",
             CommitteeChamber::Senate => "senate",
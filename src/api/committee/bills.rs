@@ -1,11 +1,16 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use chrono::{DateTime, Utc};
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::api::{committee::CommitteeChamber, endpoint::Endpoint, params::QueryParams};
+use crate::{api::committee::CommitteeChamber, api::endpoint::Endpoint, api::params::QueryParams};
 
 /// Represents the /committee/:chamber/:committeeCode/bills endpoint.
+///
+/// Bills are listed under `committee-bills.bills` rather than in a top-level list.
 #[derive(Debug, Clone, Builder)]
 #[builder(setter(strip_option))]
 pub struct Bills<'a> {
@@ -27,6 +32,18 @@ impl<'a> Bills<'a> {
     pub fn builder() -> BillsBuilder<'a> {
         BillsBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> BillsBuilder<'a> {
+        BillsBuilder {
+            chamber: Some(self.chamber),
+            committee_code: Some(self.committee_code.clone()),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+            from_date_time: Some(self.from_date_time),
+            to_date_time: Some(self.to_date_time),
+        }
+    }
 }
 
 impl Endpoint for Bills<'_> {
@@ -43,13 +60,13 @@ impl Endpoint for Bills<'_> {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
         params.push_opt("limit", self.limit);
-        params.push_opt("from_date_time", self.from_date_time);
-        params.push_opt("to_date_time", self.to_date_time);
+        params.push_opt("fromDateTime", self.from_date_time);
+        params.push_opt("toDateTime", self.to_date_time);
 
         params
     }
@@ -57,7 +74,7 @@ impl Endpoint for Bills<'_> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -72,18 +89,34 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("committee/bills/endpoint");
+
+        let endpoint = Bills::builder()
+            .chamber(CommitteeChamber::House)
+            .committee_code("hspw00")
+            .build()
+            .unwrap();
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
 
+        assert!(res["committee-bills"].is_object());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = Bills::builder()
             .chamber(CommitteeChamber::House)
             .committee_code("hspw00")
+            .offset(20)
+            .limit(10)
+            .from_date_time("2024-01-01T00:00:00Z".parse().unwrap())
+            .to_date_time("2024-12-31T00:00:00Z".parse().unwrap())
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "committee/house/hspw00/bills?offset=20&limit=10&fromDateTime=2024-01-01T00%3A00%3A00Z&toDateTime=2024-12-31T00%3A00%3A00Z"
+        );
     }
 }
@@ -1,16 +1,20 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use chrono::{DateTime, Utc};
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::api::{committee::CommitteeChamber, endpoint::Endpoint, params::QueryParams};
+use crate::{
+    api::committee::CommitteeChamber, api::endpoint::Endpoint, api::paged::Pageable,
+    api::params::QueryParams,
+};
 
-/// Represents the /committee/:congress/:chamber endpoint.
+/// Represents the /committee/:chamber endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct Chamber {
-    #[builder(setter(into))]
-    congress: u16,
     #[builder(setter(into))]
     chamber: CommitteeChamber,
     #[builder(default)]
@@ -27,6 +31,17 @@ impl Chamber {
     pub fn builder() -> ChamberBuilder {
         ChamberBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> ChamberBuilder {
+        ChamberBuilder {
+            chamber: Some(self.chamber),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+            from_date_time: Some(self.from_date_time),
+            to_date_time: Some(self.to_date_time),
+        }
+    }
 }
 
 impl Endpoint for Chamber {
@@ -35,27 +50,28 @@ impl Endpoint for Chamber {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("committee/{}/{}", self.congress, self.chamber.as_str()).into()
+        format!("committee/{}", self.chamber.as_str()).into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
         params.push_opt("limit", self.limit);
-        params.push_opt("from_date_time", self.from_date_time);
-        params.push_opt("to_date_time", self.to_date_time);
+        params.push_opt("fromDateTime", self.from_date_time);
+        params.push_opt("toDateTime", self.to_date_time);
 
         params
     }
 }
 
+impl Pageable for Chamber {}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        api::{committee::CommitteeChamber, common::Format, query::Query},
-        auth::Auth,
-        cdg::Cdg,
+        api::committee::CommitteeChamber, api::paged::Page, api::query::Query,
+        test::cassette::Cassette,
     };
 
     use super::*;
@@ -63,7 +79,6 @@ mod tests {
     #[test]
     fn is_sufficient() {
         Chamber::builder()
-            .congress(118_u16)
             .chamber(CommitteeChamber::House)
             .build()
             .unwrap();
@@ -71,18 +86,32 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("committee/chamber/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = Chamber::builder()
+            .chamber(CommitteeChamber::House)
+            .build()
+            .unwrap();
+
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
 
+        assert!(!page.items().is_empty());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = Chamber::builder()
-            .congress(118_u16)
             .chamber(CommitteeChamber::House)
+            .offset(20)
+            .limit(10)
+            .from_date_time("2024-01-01T00:00:00Z".parse().unwrap())
+            .to_date_time("2024-12-31T00:00:00Z".parse().unwrap())
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "committee/house?offset=20&limit=10&fromDateTime=2024-01-01T00%3A00%3A00Z&toDateTime=2024-12-31T00%3A00%3A00Z"
+        );
     }
 }
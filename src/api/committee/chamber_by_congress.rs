@@ -1,9 +1,15 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use chrono::{DateTime, Utc};
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::committee::CommitteeChamber, api::endpoint::Endpoint, api::params::QueryParams};
+use crate::{
+    api::committee::CommitteeChamber, api::endpoint::Endpoint, api::paged::Pageable,
+    api::params::QueryParams,
+};
 
 /// Represents the /committee/:congress/:chamber endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
@@ -27,6 +33,18 @@ impl ChamberByCongress {
     pub fn builder() -> ChamberByCongressBuilder {
         ChamberByCongressBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> ChamberByCongressBuilder {
+        ChamberByCongressBuilder {
+            congress: Some(self.congress),
+            chamber: Some(self.chamber),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+            from_date_time: Some(self.from_date_time),
+            to_date_time: Some(self.to_date_time),
+        }
+    }
 }
 
 impl Endpoint for ChamberByCongress {
@@ -38,24 +56,25 @@ impl Endpoint for ChamberByCongress {
         format!("committee/{}/{}", self.congress, self.chamber.as_str()).into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
         params.push_opt("limit", self.limit);
-        params.push_opt("from_date_time", self.from_date_time);
-        params.push_opt("to_date_time", self.to_date_time);
+        params.push_opt("fromDateTime", self.from_date_time);
+        params.push_opt("toDateTime", self.to_date_time);
 
         params
     }
 }
 
+impl Pageable for ChamberByCongress {}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        api::{committee::CommitteeChamber, common::Format, query::Query},
-        auth::Auth,
-        cdg::Cdg,
+        api::committee::CommitteeChamber, api::paged::Page, api::query::Query,
+        test::cassette::Cassette,
     };
 
     use super::*;
@@ -71,18 +90,34 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("committee/chamber_by_congress/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = ChamberByCongress::builder()
+            .congress(118_u16)
+            .chamber(CommitteeChamber::House)
+            .build()
+            .unwrap();
+
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
 
+        assert!(!page.items().is_empty());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = ChamberByCongress::builder()
             .congress(118_u16)
             .chamber(CommitteeChamber::House)
+            .offset(20)
+            .limit(10)
+            .from_date_time("2024-01-01T00:00:00Z".parse().unwrap())
+            .to_date_time("2024-12-31T00:00:00Z".parse().unwrap())
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "committee/118/house?offset=20&limit=10&fromDateTime=2024-01-01T00%3A00%3A00Z&toDateTime=2024-12-31T00%3A00%3A00Z"
+        );
     }
 }
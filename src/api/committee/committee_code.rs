@@ -1,3 +1,6 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
@@ -18,6 +21,14 @@ impl<'a> CommitteeCode<'a> {
     pub fn builder() -> CommitteeCodeBuilder<'a> {
         CommitteeCodeBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> CommitteeCodeBuilder<'a> {
+        CommitteeCodeBuilder {
+            chamber: Some(self.chamber),
+            committee_code: Some(self.committee_code.clone()),
+        }
+    }
 }
 
 impl Endpoint for CommitteeCode<'_> {
@@ -34,18 +45,14 @@ impl Endpoint for CommitteeCode<'_> {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         QueryParams::default()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{
-        api::{committee::CommitteeChamber, common::Format, query::Query},
-        auth::Auth,
-        cdg::Cdg,
-    };
+    use crate::{api::committee::CommitteeChamber, api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -60,18 +67,30 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("committee/committee_code/endpoint");
+
+        let endpoint = CommitteeCode::builder()
+            .chamber(CommitteeChamber::House)
+            .committee_code("hspw00")
+            .build()
+            .unwrap();
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
 
+        assert!(res["committee"].is_object());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = CommitteeCode::builder()
             .chamber(CommitteeChamber::House)
             .committee_code("hspw00")
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "committee/house/hspw00"
+        );
     }
 }
@@ -1,8 +1,14 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::committee::CommitteeChamber, api::endpoint::Endpoint, api::params::QueryParams};
+use crate::{
+    api::committee::CommitteeChamber, api::endpoint::Endpoint, api::paged::Pageable,
+    api::params::QueryParams,
+};
 
 /// Represents the /committee/:chamber/:committeeCode/nominations endpoint.
 #[derive(Debug, Clone, Builder)]
@@ -22,6 +28,16 @@ impl<'a> Nominations<'a> {
     pub fn builder() -> NominationsBuilder<'a> {
         NominationsBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> NominationsBuilder<'a> {
+        NominationsBuilder {
+            chamber: Some(self.chamber),
+            committee_code: Some(self.committee_code.clone()),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+        }
+    }
 }
 
 impl Endpoint for Nominations<'_> {
@@ -38,7 +54,7 @@ impl Endpoint for Nominations<'_> {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
@@ -48,12 +64,13 @@ impl Endpoint for Nominations<'_> {
     }
 }
 
+impl Pageable for Nominations<'_> {}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        api::{committee::CommitteeChamber, common::Format, query::Query},
-        auth::Auth,
-        cdg::Cdg,
+        api::committee::CommitteeChamber, api::paged::Page, api::query::Query,
+        test::cassette::Cassette,
     };
 
     use super::*;
@@ -69,18 +86,32 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("committee/nominations/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = Nominations::builder()
+            .chamber(CommitteeChamber::House)
+            .committee_code("hspw00")
+            .build()
+            .unwrap();
+
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
 
+        assert!(!page.items().is_empty());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = Nominations::builder()
             .chamber(CommitteeChamber::House)
             .committee_code("hspw00")
+            .offset(20)
+            .limit(10)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "committee/house/hspw00/nominations?offset=20&limit=10"
+        );
     }
 }
@@ -1,8 +1,14 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::committee::CommitteeChamber, api::endpoint::Endpoint, api::params::QueryParams};
+use crate::{
+    api::committee::CommitteeChamber, api::endpoint::Endpoint, api::paged::Pageable,
+    api::params::QueryParams,
+};
 
 /// Represents the /committee/:chamber/:committeeCode/senate-communication endpoint.
 #[derive(Debug, Clone, Builder)]
@@ -22,6 +28,16 @@ impl<'a> SenateCommunication<'a> {
     pub fn builder() -> SenateCommunicationBuilder<'a> {
         SenateCommunicationBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> SenateCommunicationBuilder<'a> {
+        SenateCommunicationBuilder {
+            chamber: Some(self.chamber),
+            committee_code: Some(self.committee_code.clone()),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+        }
+    }
 }
 
 impl Endpoint for SenateCommunication<'_> {
@@ -38,7 +54,7 @@ impl Endpoint for SenateCommunication<'_> {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
@@ -48,9 +64,11 @@ impl Endpoint for SenateCommunication<'_> {
     }
 }
 
+impl Pageable for SenateCommunication<'_> {}
+
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::paged::Page, api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -65,18 +83,32 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("committee/senate_communication/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = SenateCommunication::builder()
+            .chamber(CommitteeChamber::Senate)
+            .committee_code("ssas00")
+            .build()
+            .unwrap();
+
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
 
+        assert!(!page.items().is_empty());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = SenateCommunication::builder()
             .chamber(CommitteeChamber::Senate)
             .committee_code("ssas00")
+            .offset(20)
+            .limit(10)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "committee/senate/ssas00/senate-communication?offset=20&limit=10"
+        );
     }
 }
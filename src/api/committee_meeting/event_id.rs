@@ -1,3 +1,6 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
@@ -5,6 +8,8 @@ use std::borrow::Cow;
 use crate::{api::common::CommitteeChamber, api::endpoint::Endpoint, api::params::QueryParams};
 
 /// Represents the /committee-meeting/:congress/:chamber/:eventId endpoint.
+///
+/// The response is a single `committeeMeeting` rather than a list.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct EventId {
@@ -24,6 +29,17 @@ impl EventId {
     pub fn builder() -> EventIdBuilder {
         EventIdBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> EventIdBuilder {
+        EventIdBuilder {
+            congress: Some(self.congress),
+            chamber: Some(self.chamber),
+            event_id: Some(self.event_id),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+        }
+    }
 }
 
 impl Endpoint for EventId {
@@ -41,7 +57,7 @@ impl Endpoint for EventId {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
@@ -53,7 +69,7 @@ impl Endpoint for EventId {
 
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -69,19 +85,34 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("committee_meeting/event_id/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = EventId::builder()
+            .congress(118_u16)
+            .chamber(CommitteeChamber::House)
+            .event_id(115538_u32)
+            .build()
+            .unwrap();
+
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
 
+        assert!(res["committeeMeeting"].is_object());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = EventId::builder()
             .congress(118_u16)
             .chamber(CommitteeChamber::House)
             .event_id(115538_u32)
+            .offset(20)
+            .limit(10)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "committee-meeting/118/house/115538?offset=20&limit=10"
+        );
     }
 }
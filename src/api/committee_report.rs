@@ -26,7 +26,7 @@ pub enum CommitteeReportType {
 }
 
 impl CommitteeReportType {
-    fn as_str(self) -> &'static str {
+    pub(crate) fn as_str(self) -> &'static str {
         match self {
             CommitteeReportType::Hrpt => "hrpt",
             CommitteeReportType::Srpt => "srpt",
@@ -1,10 +1,13 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::params::QueryParams};
-
-use super::CommitteeReportType;
+use crate::{
+    api::committee_report::CommitteeReportType, api::endpoint::Endpoint, api::params::QueryParams,
+};
 
 /// Represents the /committee-report/:congress/:reportType/:reportNumber endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
@@ -22,6 +25,15 @@ impl ReportNumber {
     pub fn builder() -> ReportNumberBuilder {
         ReportNumberBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> ReportNumberBuilder {
+        ReportNumberBuilder {
+            congress: Some(self.congress),
+            report_type: Some(self.report_type),
+            report_number: Some(self.report_number),
+        }
+    }
 }
 
 impl Endpoint for ReportNumber {
@@ -39,14 +51,14 @@ impl Endpoint for ReportNumber {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         QueryParams::default()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -62,12 +74,22 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("committee_report/report_number/endpoint");
+
+        let endpoint = ReportNumber::builder()
+            .congress(118_u16)
+            .report_type(CommitteeReportType::Hrpt)
+            .report_number(617_u32)
+            .build()
+            .unwrap();
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
 
+        assert!(res["committeeReports"].is_array());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = ReportNumber::builder()
             .congress(118_u16)
             .report_type(CommitteeReportType::Hrpt)
@@ -75,6 +97,9 @@ mod tests {
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "committee-report/118/hrpt/617"
+        );
     }
 }
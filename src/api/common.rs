@@ -71,18 +71,13 @@ impl BillType {
 
 /// The congress.gov API can return data in either Json or XML
 /// format. The default for this crate is Json.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Format {
+    #[default]
     Json,
     Xml,
 }
 
-impl Default for Format {
-    fn default() -> Self {
-        Self::Json
-    }
-}
-
 impl From<Format> for Cow<'static, str> {
     fn from(format: Format) -> Self {
         format.as_str().into()
@@ -106,21 +101,16 @@ impl Format {
 }
 
 /// Certain endpoints allow the response to be sorted
-/// in either Ascending or Descending order.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// in either Ascending or Descending order of update date.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Sort {
     /// Ascending order
+    #[default]
     Asc,
     /// Descending order
     Desc,
 }
 
-impl Default for Sort {
-    fn default() -> Self {
-        Self::Asc
-    }
-}
-
 impl Sort {
     pub fn as_str(self) -> &'static str {
         match self {
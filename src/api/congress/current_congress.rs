@@ -1,3 +1,6 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
@@ -5,6 +8,8 @@ use std::borrow::Cow;
 use crate::{api::endpoint::Endpoint, api::params::QueryParams};
 
 /// Represents the /congress/current endpoint.
+///
+/// The response is a single `congress` rather than a list.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct CurrentCongress {
@@ -18,6 +23,14 @@ impl CurrentCongress {
     pub fn builder() -> CurrentCongressBuilder {
         CurrentCongressBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> CurrentCongressBuilder {
+        CurrentCongressBuilder {
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+        }
+    }
 }
 
 impl Endpoint for CurrentCongress {
@@ -29,7 +42,7 @@ impl Endpoint for CurrentCongress {
         "congress/current".into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
@@ -41,7 +54,7 @@ impl Endpoint for CurrentCongress {
 
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -52,14 +65,26 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
-
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let client = Cassette::new("congress/current_congress/endpoint");
 
         let endpoint = CurrentCongress::builder().build().unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
+
+        assert!(res["congress"].is_object());
+    }
+
+    #[test]
+    fn endpoint_url() {
+        let endpoint = CurrentCongress::builder()
+            .offset(20)
+            .limit(10)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "congress/current?offset=20&limit=10"
+        );
     }
 }
@@ -1,3 +1,6 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
@@ -9,13 +12,20 @@ use crate::{api::endpoint::Endpoint, api::params::QueryParams};
 #[builder(setter(strip_option))]
 pub struct SpecificCongress {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
 }
 
 impl SpecificCongress {
     pub fn builder() -> SpecificCongressBuilder {
         SpecificCongressBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> SpecificCongressBuilder {
+        SpecificCongressBuilder {
+            congress: Some(self.congress),
+        }
+    }
 }
 
 impl Endpoint for SpecificCongress {
@@ -27,14 +37,14 @@ impl Endpoint for SpecificCongress {
         format!("congress/{}", self.congress).into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         QueryParams::default()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -48,17 +58,25 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("congress/specific_congress/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = SpecificCongress::builder()
+            .congress(117_u8)
+            .build()
+            .unwrap();
+
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
 
+        assert!(res["congress"].is_object());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = SpecificCongress::builder()
             .congress(117_u8)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(crate::test::endpoint_url(&endpoint), "congress/117");
     }
 }
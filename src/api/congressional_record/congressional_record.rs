@@ -1,3 +1,6 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
@@ -5,6 +8,8 @@ use std::borrow::Cow;
 use crate::{api::endpoint::Endpoint, api::params::QueryParams};
 
 /// Represents the /congressional-record endpoint.
+///
+/// Issues are listed under `Results.Issues` rather than in a top-level list.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct CongressionalRecord {
@@ -24,6 +29,17 @@ impl CongressionalRecord {
     pub fn builder() -> CongressionalRecordBuilder {
         CongressionalRecordBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> CongressionalRecordBuilder {
+        CongressionalRecordBuilder {
+            year: Some(self.year),
+            month: Some(self.month),
+            day: Some(self.day),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+        }
+    }
 }
 
 impl Endpoint for CongressionalRecord {
@@ -32,10 +48,10 @@ impl Endpoint for CongressionalRecord {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        "congressional-record".to_string().into()
+        "congressional-record".into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("y", self.year);
@@ -50,7 +66,7 @@ impl Endpoint for CongressionalRecord {
 
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -61,14 +77,29 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
-
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let client = Cassette::new("congressional_record/congressional_record/endpoint");
 
         let endpoint = CongressionalRecord::builder().build().unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
+
+        assert!(res["Results"].is_object());
+    }
+
+    #[test]
+    fn endpoint_url() {
+        let endpoint = CongressionalRecord::builder()
+            .year(2024_u16)
+            .month(1_u8)
+            .day(3_u8)
+            .offset(20)
+            .limit(10)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "congressional-record?y=2024&m=1&d=3&offset=20&limit=10"
+        );
     }
 }
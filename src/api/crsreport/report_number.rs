@@ -1,3 +1,6 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
@@ -7,18 +10,25 @@ use crate::{api::endpoint::Endpoint, api::params::QueryParams};
 /// Represents the /crsreport/:reportNumber endpoint.
 #[derive(Debug, Clone, Builder)]
 #[builder(setter(strip_option))]
-pub struct ReportNumber {
+pub struct ReportNumber<'a> {
     #[builder(setter(into))]
-    report_number: Cow<'static, str>,
+    report_number: Cow<'a, str>,
 }
 
-impl ReportNumber {
-    pub fn builder() -> ReportNumberBuilder {
+impl<'a> ReportNumber<'a> {
+    pub fn builder() -> ReportNumberBuilder<'a> {
         ReportNumberBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> ReportNumberBuilder<'a> {
+        ReportNumberBuilder {
+            report_number: Some(self.report_number.clone()),
+        }
+    }
 }
 
-impl Endpoint for ReportNumber {
+impl Endpoint for ReportNumber<'_> {
     fn method(&self) -> Method {
         Method::GET
     }
@@ -27,14 +37,14 @@ impl Endpoint for ReportNumber {
         format!("crsreport/{}", self.report_number).into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         QueryParams::default()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -48,17 +58,25 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("crsreport/report_number/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = ReportNumber::builder()
+            .report_number("R47175")
+            .build()
+            .unwrap();
+
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
 
+        assert!(res["CRSReport"].is_object());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = ReportNumber::builder()
             .report_number("R47175")
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(crate::test::endpoint_url(&endpoint), "crsreport/R47175");
     }
 }
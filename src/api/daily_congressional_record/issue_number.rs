@@ -1,3 +1,6 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
@@ -5,6 +8,8 @@ use std::borrow::Cow;
 use crate::{api::endpoint::Endpoint, api::params::QueryParams};
 
 /// Represents the /daily-congressional-record/:volumeNumber/:issueNumber endpoint.
+///
+/// The response is a single `issue` rather than a list.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct IssueNumber {
@@ -22,6 +27,16 @@ impl IssueNumber {
     pub fn builder() -> IssueNumberBuilder {
         IssueNumberBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> IssueNumberBuilder {
+        IssueNumberBuilder {
+            volume_number: Some(self.volume_number),
+            issue_number: Some(self.issue_number),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+        }
+    }
 }
 
 impl Endpoint for IssueNumber {
@@ -37,7 +52,7 @@ impl Endpoint for IssueNumber {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
@@ -49,7 +64,7 @@ impl Endpoint for IssueNumber {
 
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -64,18 +79,32 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("daily_congressional_record/issue_number/endpoint");
+
+        let endpoint = IssueNumber::builder()
+            .volume_number(168_u32)
+            .issue_number(153_u32)
+            .build()
+            .unwrap();
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
 
+        assert!(res["issue"].is_object());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = IssueNumber::builder()
             .volume_number(168_u32)
             .issue_number(153_u32)
+            .offset(20)
+            .limit(10)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "daily-congressional-record/168/153?offset=20&limit=10"
+        );
     }
 }
@@ -1,11 +1,13 @@
-use http::{Method, Request};
-use serde::de::DeserializeOwned;
-use std::borrow::Cow;
+use bytes::Bytes;
+use http::{Method, Response};
+use std::{borrow::Cow, time::Duration};
 use url::Url;
 
-use crate::{api::client::Client, api::error::ApiError, api::params::QueryParams};
+use crate::{
+    api::client::Client, api::common::Format, api::error::ApiError, api::params::QueryParams,
+};
 
-use super::{Query, query};
+use super::{Query, RawQuery, query, response::FromResponse, timeout::with_timeout};
 
 /// The URL base for the congress.gov REST API.
 /// Currently, there is only one variant for the current
@@ -41,42 +43,72 @@ pub trait Endpoint {
         UrlBase::ApiV3
     }
 
+    /// The response format for the endpoint. When `None`, the client's
+    /// format is used.
+    fn format(&self) -> Option<Format> {
+        None
+    }
+
+    /// The maximum duration of a query of the endpoint. When `None`, the
+    /// client's timeout is used.
+    fn timeout(&self) -> Option<Duration> {
+        None
+    }
+
     /// Query parameters for the endpoint.
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         QueryParams::default()
     }
 }
 
-impl<E, T, C> Query<T, C> for E
+/// Builds the full URL for an endpoint, including its parameters, the
+/// response format and auth. The format defaults to the endpoint's, then
+/// the client's.
+pub(crate) fn request_url<E, C>(
+    endpoint: &E,
+    client: &C,
+    format: Option<Format>,
+) -> Result<(Url, Format), ApiError<C::Error>>
 where
     E: Endpoint,
-    T: DeserializeOwned,
     C: Client,
 {
-    async fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
-        let mut url = self.url_base().endpoint_for(client, &self.endpoint())?;
-        self.parameters().add_to_url(&mut url);
-        client.set_auth(&mut url);
+    let mut url = endpoint
+        .url_base()
+        .endpoint_for(client, &endpoint.endpoint())?;
+    endpoint.parameters().add_to_url(&mut url);
+    let format = format
+        .or_else(|| endpoint.format())
+        .unwrap_or_else(|| client.format());
+    url.query_pairs_mut().append_pair("format", format.as_str());
+    client.set_auth(&mut url);
 
-        let req = Request::builder()
-            .method(self.method())
-            .uri(query::url_to_http_uri(url).map_err(|e| ApiError::Cdg { source: e })?);
-
-        let rsp = client.rest(req, Vec::new()).await?;
+    Ok((url, format))
+}
 
-        let status = rsp.status();
+impl<E, C> RawQuery<C> for E
+where
+    E: Endpoint,
+    C: Client,
+{
+    async fn raw_query(&self, client: &C) -> Result<Response<Bytes>, ApiError<C::Error>> {
+        let (url, format) = request_url(self, client, None)?;
+        let query = query::raw_query_url(client, self.method(), url, format);
 
-        let val = match serde_json::from_slice(rsp.body()) {
-            Ok(val) => val,
-            _ => {
-                return Err(ApiError::Http { status });
-            }
-        };
+        with_timeout(self.timeout().or(client.timeout()), &self.endpoint(), query).await
+    }
+}
 
-        if !status.is_success() {
-            return Err(ApiError::Http { status });
-        }
+impl<E, T, C> Query<T, C> for E
+where
+    E: Endpoint,
+    T: FromResponse,
+    C: Client,
+{
+    async fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let (url, format) = request_url(self, client, T::format())?;
+        let query = query::query_url(client, self.method(), url, format);
 
-        serde_json::from_value::<T>(val).map_err(|e| ApiError::DataType { source: e })
+        with_timeout(self.timeout().or(client.timeout()), &self.endpoint(), query).await
     }
 }
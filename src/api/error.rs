@@ -1,9 +1,132 @@
 //! API Errors.
 
-use std::error::Error;
+use bytes::Bytes;
+use http::StatusCode;
+use serde::Deserialize;
+use std::{borrow::Cow, error::Error, fmt, time::Duration};
 use thiserror::Error;
+use url::Url;
 
-use crate::cdg::CdgError;
+use crate::api::common::Format;
+
+/// The value substituted for the API key in URLs included in errors.
+pub(crate) const REDACTED: &str = "REDACTED";
+
+/// Returns a copy of the URL with the value of any `api_key` parameter
+/// replaced, so that it may be safely displayed or logged.
+pub(crate) fn redact_url(url: &Url) -> Url {
+    if !url.query_pairs().any(|(key, _)| key == "api_key") {
+        return url.clone();
+    }
+
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(key, value)| {
+            let value = if key == "api_key" {
+                REDACTED.into()
+            } else {
+                value.into_owned()
+            };
+            (key.into_owned(), value)
+        })
+        .collect();
+    let mut url = url.clone();
+    url.query_pairs_mut().clear().extend_pairs(pairs);
+    url
+}
+
+/// Replaces the value of any `api_key` query parameter appearing in text,
+/// such as the message of an HTTP client error which includes the URL.
+pub(crate) fn redact_str(text: &str) -> Cow<'_, str> {
+    const PARAM: &str = "api_key=";
+    if !text.contains(PARAM) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut redacted = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(PARAM) {
+        let (before, after) = rest.split_at(start + PARAM.len());
+        redacted.push_str(before);
+        redacted.push_str(REDACTED);
+        let end = after
+            .find(|c: char| matches!(c, '&' | '#' | ')' | '"' | '\'' | '>') || c.is_whitespace())
+            .unwrap_or(after.len());
+        rest = &after[end..];
+    }
+    redacted.push_str(rest);
+    Cow::Owned(redacted)
+}
+
+/// The details of an error response returned by the congress.gov API.
+#[derive(Debug, Clone)]
+pub struct ErrorResponse {
+    /// The HTTP status of the response.
+    pub status: StatusCode,
+    /// The requested URL, with the API key redacted.
+    pub url: Url,
+    /// The error code from the response body, if any.
+    pub code: Option<String>,
+    /// The error message from the response body, if any.
+    pub message: Option<String>,
+    /// The raw response body.
+    pub body: Bytes,
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    error: ErrorDetail,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ErrorDetail {
+    Message(String),
+    Object {
+        code: Option<String>,
+        message: Option<String>,
+    },
+}
+
+impl ErrorResponse {
+    /// Parses the error response for a request to the given URL.
+    ///
+    /// Both the congress.gov (`{"error": "..."}`) and api.data.gov
+    /// (`{"error": {"code": "...", "message": "..."}}`) error shapes are
+    /// understood. Other bodies are kept as-is without a message.
+    pub fn new(url: &Url, status: StatusCode, body: Bytes) -> Self {
+        let (code, message) = match serde_json::from_slice::<ErrorBody>(&body) {
+            Ok(ErrorBody {
+                error: ErrorDetail::Message(message),
+            }) => (None, Some(message)),
+            Ok(ErrorBody {
+                error: ErrorDetail::Object { code, message },
+            }) => (code, message),
+            Err(_) => (None, None),
+        };
+
+        ErrorResponse {
+            status,
+            url: redact_url(url),
+            code,
+            message,
+            body,
+        }
+    }
+}
+
+impl fmt::Display for ErrorResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} for {}", self.status, self.url)?;
+        match &self.message {
+            Some(message) => write!(f, ": {}", message),
+            None if !self.body.is_empty() => {
+                write!(f, ": {}", String::from_utf8_lossy(&self.body))
+            }
+            None => Ok(()),
+        }
+    }
+}
 
 /// Error types that can be returned or occur when communicating with the
 /// congress.gov api.
@@ -12,7 +135,7 @@ pub enum ApiError<E>
 where
     E: Error + Send + Sync + 'static,
 {
-    #[error("Client Error: {}", source)]
+    #[error("Client Error: {}", redact_str(&.source.to_string()))]
     Client { source: E },
     #[error("failed to parse url: {}", source)]
     UrlParse {
@@ -24,21 +147,100 @@ where
         #[from]
         source: http::uri::InvalidUri,
     },
-    #[error("communication with gitlab: {}", source)]
-    Communication {
+    #[error("could not parse header value: {}", source)]
+    Header {
+        #[from]
+        source: http::header::InvalidHeaderValue,
+    },
+    #[error("failed to build request: {}", source)]
+    Request {
         #[from]
-        source: reqwest::Error,
+        source: http::Error,
     },
-    #[error("HTTP error: {}", status)]
-    Http { status: http::StatusCode },
+    #[error("resource not found: {}", response)]
+    NotFound { response: Box<ErrorResponse> },
+    #[error("invalid or missing API key: {}", response)]
+    InvalidApiKey { response: Box<ErrorResponse> },
+    #[error("rate limited: {}", response)]
+    RateLimited { response: Box<ErrorResponse> },
+    #[error("server error: {}", response)]
+    Server { response: Box<ErrorResponse> },
+    #[error("HTTP error: {}", response)]
+    Http { response: Box<ErrorResponse> },
     #[error("could not parse data from JSON: {}", source)]
     DataType {
         #[from]
         source: serde_json::Error,
     },
-    #[error("CDG Error: {}", source)]
-    Cdg {
+    #[cfg(feature = "xml")]
+    #[error("could not parse data from XML: {}", source)]
+    Xml {
         #[from]
-        source: CdgError,
+        source: quick_xml::DeError,
+    },
+    #[error("decoding {} responses requires the `xml` feature", .format.as_str())]
+    UnsupportedFormat { format: Format },
+    #[error("query of {} timed out after {:?}", endpoint, timeout)]
+    Timeout { endpoint: String, timeout: Duration },
+    #[error("query of {} was cancelled", endpoint)]
+    Cancelled { endpoint: String },
+    #[error("request failed after {} attempts: {}", attempts, source)]
+    Retry {
+        attempts: u32,
+        source: Box<ApiError<E>>,
     },
 }
+
+impl<E> ApiError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// Creates the error for an unsuccessful response to a request for the
+    /// given URL.
+    pub fn from_response(url: &Url, status: StatusCode, body: Bytes) -> Self {
+        let response = Box::new(ErrorResponse::new(url, status, body));
+        match status {
+            StatusCode::NOT_FOUND => ApiError::NotFound { response },
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                ApiError::InvalidApiKey { response }
+            }
+            StatusCode::TOO_MANY_REQUESTS => ApiError::RateLimited { response },
+            status if status.is_server_error() => ApiError::Server { response },
+            _ => ApiError::Http { response },
+        }
+    }
+
+    /// The error response returned by the API, if the error was caused by
+    /// an unsuccessful status.
+    pub fn response(&self) -> Option<&ErrorResponse> {
+        match self {
+            ApiError::NotFound { response }
+            | ApiError::InvalidApiKey { response }
+            | ApiError::RateLimited { response }
+            | ApiError::Server { response }
+            | ApiError::Http { response } => Some(response),
+            ApiError::Retry { source, .. } => source.response(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_text() {
+        assert_eq!(
+            redact_str(
+                "error sending request for url (http://localhost/v3/bill?api_key=SECRET&limit=1)"
+            ),
+            "error sending request for url (http://localhost/v3/bill?api_key=REDACTED&limit=1)"
+        );
+        assert_eq!(
+            redact_str("http://localhost/?api_key=SECRET"),
+            "http://localhost/?api_key=REDACTED"
+        );
+        assert_eq!(redact_str("no key here"), "no key here");
+    }
+}
@@ -0,0 +1,70 @@
+//! Per-query response format overrides.
+
+use http::Method;
+use std::{borrow::Cow, time::Duration};
+
+use crate::api::{
+    common::Format,
+    endpoint::{Endpoint, UrlBase},
+    paged::Pageable,
+    params::QueryParams,
+};
+
+/// Wraps an endpoint so that it is queried in a specific format, regardless
+/// of the format configured on the client.
+#[derive(Debug, Clone)]
+pub struct Formatted<E> {
+    endpoint: E,
+    format: Format,
+}
+
+/// Query an endpoint in the given format.
+pub fn formatted<E>(endpoint: E, format: Format) -> Formatted<E> {
+    Formatted { endpoint, format }
+}
+
+impl<E> Endpoint for Formatted<E>
+where
+    E: Endpoint,
+{
+    fn method(&self) -> Method {
+        self.endpoint.method()
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.endpoint.endpoint()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        self.endpoint.url_base()
+    }
+
+    fn format(&self) -> Option<Format> {
+        Some(self.format)
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.endpoint.timeout()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        self.endpoint.parameters()
+    }
+}
+
+impl<E> Pageable for Formatted<E> where E: Pageable {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{self, bill, common::Format, endpoint::Endpoint};
+
+    #[test]
+    fn overrides_format() {
+        let endpoint = bill::Bill::builder().build().unwrap();
+        assert_eq!(endpoint.format(), None);
+
+        let endpoint = api::formatted(endpoint, Format::Xml);
+        assert_eq!(endpoint.format(), Some(Format::Xml));
+        assert_eq!(endpoint.endpoint(), "bill");
+    }
+}
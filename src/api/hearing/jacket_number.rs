@@ -1,3 +1,6 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
@@ -20,6 +23,15 @@ impl JacketNumber {
     pub fn builder() -> JacketNumberBuilder {
         JacketNumberBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> JacketNumberBuilder {
+        JacketNumberBuilder {
+            congress: Some(self.congress),
+            chamber: Some(self.chamber),
+            jacket_number: Some(self.jacket_number),
+        }
+    }
 }
 
 impl Endpoint for JacketNumber {
@@ -37,14 +49,14 @@ impl Endpoint for JacketNumber {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         QueryParams::default()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -60,12 +72,22 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("hearing/jacket_number/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = JacketNumber::builder()
+            .congress(116_u16)
+            .chamber(CommitteeChamber::House)
+            .jacket_number(41365_u32)
+            .build()
+            .unwrap();
+
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
 
+        assert!(res["hearing"].is_object());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = JacketNumber::builder()
             .congress(116_u16)
             .chamber(CommitteeChamber::House)
@@ -73,6 +95,9 @@ mod tests {
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "hearing/116/house/41365"
+        );
     }
 }
@@ -25,7 +25,7 @@ pub enum HouseCommunicationType {
 }
 
 impl HouseCommunicationType {
-    fn as_str(self) -> &'static str {
+    pub(crate) fn as_str(self) -> &'static str {
         match self {
             HouseCommunicationType::Ec => "ec",
             HouseCommunicationType::Ml => "ml",
@@ -1,10 +1,14 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::params::QueryParams};
-
-use super::HouseCommunicationType;
+use crate::{
+    api::endpoint::Endpoint, api::house_communication::HouseCommunicationType,
+    api::params::QueryParams,
+};
 
 /// Represents the /house-communication/:congress/:communicationType/:communicationNumber endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
@@ -22,6 +26,15 @@ impl CommunicationNumber {
     pub fn builder() -> CommunicationNumberBuilder {
         CommunicationNumberBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> CommunicationNumberBuilder {
+        CommunicationNumberBuilder {
+            congress: Some(self.congress),
+            communication_type: Some(self.communication_type),
+            communication_number: Some(self.communication_number),
+        }
+    }
 }
 
 impl Endpoint for CommunicationNumber {
@@ -39,14 +52,14 @@ impl Endpoint for CommunicationNumber {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         QueryParams::default()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -62,12 +75,22 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("house_communication/communication_number/endpoint");
+
+        let endpoint = CommunicationNumber::builder()
+            .congress(117_u16)
+            .communication_type(HouseCommunicationType::Ec)
+            .communication_number(3324_u32)
+            .build()
+            .unwrap();
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
 
+        assert!(res["house-communication"].is_object());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = CommunicationNumber::builder()
             .congress(117_u16)
             .communication_type(HouseCommunicationType::Ec)
@@ -75,6 +98,9 @@ mod tests {
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "house-communication/117/ec/3324"
+        );
     }
 }
@@ -1,3 +1,6 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
@@ -16,6 +19,13 @@ impl RequirementNumber {
     pub fn builder() -> RequirementNumberBuilder {
         RequirementNumberBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> RequirementNumberBuilder {
+        RequirementNumberBuilder {
+            requirement_number: Some(self.requirement_number),
+        }
+    }
 }
 
 impl Endpoint for RequirementNumber {
@@ -27,14 +37,14 @@ impl Endpoint for RequirementNumber {
         format!("house-requirement/{}", self.requirement_number).into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         QueryParams::default()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -48,17 +58,28 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("house_requirement/requirement_number/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = RequirementNumber::builder()
+            .requirement_number(8070_u32)
+            .build()
+            .unwrap();
+
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
 
+        assert!(res["houseRequirement"].is_object());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = RequirementNumber::builder()
             .requirement_number(8070_u32)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "house-requirement/8070"
+        );
     }
 }
@@ -21,25 +21,20 @@ mod law_number;
 mod law_type;
 
 /// The possible law types in Congress. Also known as 'slip laws.'
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CongressionalLawType {
     /// Public laws affect society as a whole
+    #[default]
     Public,
     /// Private laws affect an individual, family, or small group
     Private,
 }
 
 impl CongressionalLawType {
-    fn as_str(self) -> &'static str {
+    pub(crate) fn as_str(self) -> &'static str {
         match self {
             CongressionalLawType::Public => "pub",
             CongressionalLawType::Private => "priv",
         }
     }
 }
-
-impl Default for CongressionalLawType {
-    fn default() -> Self {
-        Self::Public
-    }
-}
@@ -1,20 +1,23 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::params::QueryParams};
-
-use super::CongressionalLawType;
+use crate::{api::endpoint::Endpoint, api::law::CongressionalLawType, api::params::QueryParams};
 
 /// Represents the /law/:congress/:lawType/:lawNumber endpoint.
+///
+/// The response is a single `bill` rather than a list.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct LawNumber {
     #[builder(setter(into))]
     congress: u16,
-    #[builder(default)]
+    #[builder(setter(into))]
     law_type: CongressionalLawType,
-    #[builder(default)]
+    #[builder(setter(into))]
     law_number: u32,
     #[builder(default)]
     offset: Option<u32>,
@@ -26,6 +29,17 @@ impl LawNumber {
     pub fn builder() -> LawNumberBuilder {
         LawNumberBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> LawNumberBuilder {
+        LawNumberBuilder {
+            congress: Some(self.congress),
+            law_type: Some(self.law_type),
+            law_number: Some(self.law_number),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+        }
+    }
 }
 
 impl Endpoint for LawNumber {
@@ -43,7 +57,7 @@ impl Endpoint for LawNumber {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
@@ -55,11 +69,7 @@ impl Endpoint for LawNumber {
 
 #[cfg(test)]
 mod tests {
-    use crate::{
-        api::{common::Format, law::law_number::LawNumber, query::Query},
-        auth::Auth,
-        cdg::Cdg,
-    };
+    use crate::{api::law::law_number::LawNumber, api::query::Query, test::cassette::Cassette};
 
     use super::CongressionalLawType;
 
@@ -68,26 +78,41 @@ mod tests {
         LawNumber::builder()
             .congress(118_u16)
             .law_type(super::CongressionalLawType::Public)
-            .law_number(108)
+            .law_number(108_u32)
             .build()
             .unwrap();
     }
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("law/law_number/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = LawNumber::builder()
+            .congress(118_u16)
+            .law_type(CongressionalLawType::Public)
+            .law_number(108_u32)
+            .build()
+            .unwrap();
 
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
+
+        assert!(res["bill"].is_object());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = LawNumber::builder()
             .congress(118_u16)
             .law_type(CongressionalLawType::Public)
-            .law_number(108)
+            .law_number(108_u32)
+            .offset(20)
+            .limit(10)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "law/118/pub/108?offset=20&limit=10"
+        );
     }
 }
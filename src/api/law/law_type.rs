@@ -1,8 +1,14 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::law::CongressionalLawType, api::params::QueryParams};
+use crate::{
+    api::endpoint::Endpoint, api::law::CongressionalLawType, api::paged::Pageable,
+    api::params::QueryParams,
+};
 
 /// Represents the /law/:congress/:lawType endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
@@ -10,7 +16,7 @@ use crate::{api::endpoint::Endpoint, api::law::CongressionalLawType, api::params
 pub struct LawType {
     #[builder(setter(into))]
     congress: u16,
-    #[builder(default)]
+    #[builder(setter(into))]
     law_type: CongressionalLawType,
     #[builder(default)]
     offset: Option<u32>,
@@ -22,6 +28,16 @@ impl LawType {
     pub fn builder() -> LawTypeBuilder {
         LawTypeBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> LawTypeBuilder {
+        LawTypeBuilder {
+            congress: Some(self.congress),
+            law_type: Some(self.law_type),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+        }
+    }
 }
 
 impl Endpoint for LawType {
@@ -33,7 +49,7 @@ impl Endpoint for LawType {
         format!("law/{}/{}", self.congress, self.law_type.as_str()).into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
@@ -43,9 +59,11 @@ impl Endpoint for LawType {
     }
 }
 
+impl Pageable for LawType {}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Cdg, api::common::Format, api::query::Query, auth::Auth};
+    use crate::{api::paged::Page, api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -60,18 +78,32 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("law/law_type/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = LawType::builder()
+            .congress(118_u16)
+            .law_type(CongressionalLawType::Public)
+            .build()
+            .unwrap();
+
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
 
+        assert!(!page.items().is_empty());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = LawType::builder()
             .congress(118_u16)
             .law_type(CongressionalLawType::Public)
+            .offset(20)
+            .limit(10)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "law/118/pub?offset=20&limit=10"
+        );
     }
 }
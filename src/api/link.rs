@@ -0,0 +1,351 @@
+//! Links to related resources embedded in responses.
+
+use http::Method;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{borrow::Cow, fmt, str::FromStr};
+use thiserror::Error;
+use url::Url;
+
+use crate::api::{
+    endpoint::Endpoint,
+    paged::{Pageable, Paged, Pagination, paged},
+    params::QueryParams,
+    route::{Route, RouteError},
+};
+
+/// Errors which may occur when creating a [`Link`].
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum LinkError {
+    #[error("failed to parse url: {}", source)]
+    UrlParse {
+        #[from]
+        source: url::ParseError,
+    },
+    #[error("not a congress.gov API URL: {}", path)]
+    NotApi { path: String },
+}
+
+/// A `url` field of a response, pointing to a related API resource.
+///
+/// Most objects returned by the API link to related resources, e.g. the `actions.url` of a bill
+/// or the `sponsoredLegislation.url` of a member. A `Link` deserializes from such a field and is
+/// an [`Endpoint`], so it can be queried with the same client as the original request. The
+/// client's base URL, auth and format are applied to the request, while the other query
+/// parameters of the link are kept. Links to lists can be paged with [`Link::paged`].
+///
+/// ```rust,no_run
+/// # use serde::Deserialize;
+/// # use congressdotgov_rs::api::{Link, Query, bill, common::BillType};
+/// # use congressdotgov_rs::{Auth, Cdg};
+/// #[derive(Debug, Deserialize)]
+/// struct Actions {
+///     url: Link,
+/// }
+///
+/// #[derive(Debug, Deserialize)]
+/// struct Bill {
+///     actions: Actions,
+/// }
+///
+/// #[derive(Debug, Deserialize)]
+/// struct Details {
+///     bill: Bill,
+/// }
+///
+/// # tokio_test::block_on(async {
+/// # let client = Cdg::builder().auth(Auth::Token("API_KEY".into())).build().unwrap();
+/// let endpoint = bill::BillNumber::builder()
+///     .congress(117_u16)
+///     .bill_type(BillType::Hr)
+///     .bill_number(3076_u32)
+///     .build()
+///     .unwrap();
+/// let details: Details = endpoint.query(&client).await.unwrap();
+/// let actions: serde_json::Value = details.bill.actions.url.query(&client).await.unwrap();
+/// # })
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link {
+    url: Url,
+    path: String,
+}
+
+impl Link {
+    /// The URL of the link, as returned by the API. Any API key is removed.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// The path of the resource, relative to the API root, e.g. `bill/117/hr/3076/actions`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Collect the items of the list the link points to, e.g. the actions of a bill.
+    ///
+    /// Links are not [`Pageable`] themselves, as many of them point to a single item such as a
+    /// bill. The link is resolved to its [`Route`] first, and only links to a pageable endpoint
+    /// are accepted.
+    pub fn paged(self, pagination: Pagination) -> Result<Paged<ListLink>, RouteError> {
+        if !Route::try_from(&self)?.is_pageable() {
+            return Err(RouteError::NotPageable { path: self.path });
+        }
+
+        Ok(paged(ListLink(self), pagination))
+    }
+}
+
+/// A [`Link`] to a list of items, such as the actions of a bill.
+///
+/// Created by [`Link::paged`] once the link is known to point to a [`Pageable`] endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListLink(Link);
+
+impl ListLink {
+    /// The link to the list.
+    pub fn link(&self) -> &Link {
+        &self.0
+    }
+}
+
+impl TryFrom<Url> for Link {
+    type Error = LinkError;
+
+    fn try_from(mut url: Url) -> Result<Self, Self::Error> {
+        // The key is re-applied by the client, so don't keep it around to leak.
+        strip_api_key(&mut url);
+
+        let not_api = || LinkError::NotApi {
+            path: url.path().into(),
+        };
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(not_api());
+        }
+        let path = url
+            .path()
+            .split_once("/v3/")
+            .map(|(_, path)| path.trim_end_matches('/'))
+            .filter(|path| !path.is_empty())
+            .ok_or_else(not_api)?
+            .to_string();
+
+        Ok(Link { url, path })
+    }
+}
+
+impl FromStr for Link {
+    type Err = LinkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Url::parse(s)?.try_into()
+    }
+}
+
+impl fmt::Display for Link {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.url.fmt(f)
+    }
+}
+
+impl<'de> Deserialize<'de> for Link {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let url = String::deserialize(deserializer)?;
+        url.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for Link {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.url.as_str())
+    }
+}
+
+impl Endpoint for Link {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.path.clone().into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        for (key, value) in self.url.query_pairs() {
+            if key != "format" && key != "api_key" {
+                params.push(key, value);
+            }
+        }
+        params
+    }
+}
+
+/// Removes any `api_key` query parameter from a URL.
+pub(crate) fn strip_api_key(url: &mut Url) {
+    if !url.query_pairs().any(|(key, _)| key == "api_key") {
+        return;
+    }
+
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(key, _)| key != "api_key")
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+    url.set_query(None);
+    if !pairs.is_empty() {
+        url.query_pairs_mut().extend_pairs(pairs);
+    }
+}
+
+impl Endpoint for ListLink {
+    fn method(&self) -> Method {
+        self.0.method()
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.0.endpoint()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        self.0.parameters()
+    }
+}
+
+impl Pageable for ListLink {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        api::{self, Query},
+        test_util::{MockClient, MockResponse},
+    };
+
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Actions {
+        count: u64,
+        url: Link,
+    }
+
+    #[test]
+    fn deserializes_url_field() {
+        let actions: Actions = serde_json::from_value(json!({
+            "count": 3,
+            "url": "https://api.congress.gov/v3/bill/117/hr/3076/actions?format=json",
+        }))
+        .unwrap();
+
+        assert_eq!(actions.count, 3);
+        assert_eq!(actions.url.path(), "bill/117/hr/3076/actions");
+        assert_eq!(actions.url.endpoint(), "bill/117/hr/3076/actions");
+        assert_eq!(
+            serde_json::to_value(&actions.url).unwrap(),
+            json!("https://api.congress.gov/v3/bill/117/hr/3076/actions?format=json")
+        );
+    }
+
+    #[test]
+    fn drops_format_and_key() {
+        let link: Link = "https://api.congress.gov/v3/member/L000174/sponsored-legislation?format=xml&api_key=SECRET&offset=20"
+            .parse()
+            .unwrap();
+
+        let mut url = Url::parse("https://example.com/").unwrap();
+        link.parameters().add_to_url(&mut url);
+
+        assert_eq!(url.query(), Some("offset=20"));
+        assert!(!link.url().as_str().contains("SECRET"));
+    }
+
+    #[test]
+    fn rejects_other_urls() {
+        let err = "https://www.congress.gov/bill/117th-congress/house-bill/3076"
+            .parse::<Link>()
+            .unwrap_err();
+        assert!(matches!(err, LinkError::NotApi { .. }));
+        assert_eq!(
+            err.to_string(),
+            "not a congress.gov API URL: /bill/117th-congress/house-bill/3076"
+        );
+
+        assert!(matches!(
+            "bill/117".parse::<Link>(),
+            Err(LinkError::UrlParse { .. })
+        ));
+        assert!(serde_json::from_value::<Link>(json!("https://api.congress.gov/v3/")).is_err());
+    }
+
+    #[tokio::test]
+    async fn queries_link() {
+        let client = MockClient::new();
+        client.expect(
+            MockResponse::builder()
+                .endpoint("bill/117/hr/3076/actions")
+                .add_query_param("offset", "250")
+                .body(json!({ "actions": [] }))
+                .build()
+                .unwrap(),
+        );
+        let link: Link =
+            "https://api.congress.gov/v3/bill/117/hr/3076/actions?offset=250&format=xml"
+                .parse()
+                .unwrap();
+
+        let rsp: api::Response<serde_json::Value> = link.query(&client).await.unwrap();
+
+        assert_eq!(rsp.body(), &json!({ "actions": [] }));
+        assert_eq!(
+            rsp.url().as_str(),
+            "https://api.congress.gov/v3/bill/117/hr/3076/actions?offset=250&format=json"
+        );
+    }
+
+    #[tokio::test]
+    async fn pages_list_link() {
+        let client = MockClient::new();
+        client.expect(
+            MockResponse::builder()
+                .endpoint("bill/117/hr/3076/actions")
+                .body(json!({ "actions": [1, 2], "pagination": { "count": 2 } }))
+                .build()
+                .unwrap(),
+        );
+        let link: Link = "https://api.congress.gov/v3/bill/117/hr/3076/actions?format=json"
+            .parse()
+            .unwrap();
+
+        let actions: Vec<u32> = link
+            .paged(api::Pagination::All)
+            .unwrap()
+            .query(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(actions, [1, 2]);
+    }
+
+    #[test]
+    fn rejects_paging_detail_link() {
+        let link: Link = "https://api.congress.gov/v3/bill/117/hr/3076?format=json"
+            .parse()
+            .unwrap();
+
+        let err = link.paged(api::Pagination::All).unwrap_err();
+
+        assert!(matches!(&err, RouteError::NotPageable { path } if path == "bill/117/hr/3076"));
+        assert_eq!(
+            err.to_string(),
+            "bill/117/hr/3076 does not respond with a list"
+        );
+    }
+}
@@ -90,7 +90,7 @@ pub enum CongressionalStateCode {
 }
 
 impl CongressionalStateCode {
-    fn as_str(self) -> &'static str {
+    pub(crate) fn as_str(self) -> &'static str {
         use CongressionalStateCode::*;
         match self {
             AL => "AL",
@@ -1,3 +1,6 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
@@ -16,6 +19,13 @@ impl<'a> BioguideId<'a> {
     pub fn builder() -> BioguideIdBuilder<'a> {
         BioguideIdBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> BioguideIdBuilder<'a> {
+        BioguideIdBuilder {
+            bioguide_id: Some(self.bioguide_id.clone()),
+        }
+    }
 }
 
 impl Endpoint for BioguideId<'_> {
@@ -27,14 +37,14 @@ impl Endpoint for BioguideId<'_> {
         format!("member/{}", self.bioguide_id).into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         QueryParams::default()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -48,17 +58,25 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("member/bioguide_id/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = BioguideId::builder()
+            .bioguide_id("L000174")
+            .build()
+            .unwrap();
+
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
 
+        assert!(res["member"].is_object());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = BioguideId::builder()
             .bioguide_id("L000174")
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(crate::test::endpoint_url(&endpoint), "member/L000174");
     }
 }
@@ -1,3 +1,6 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
@@ -26,6 +29,16 @@ impl CongressStateCodeDistrict {
     pub fn builder() -> CongressStateCodeDistrictBuilder {
         CongressStateCodeDistrictBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> CongressStateCodeDistrictBuilder {
+        CongressStateCodeDistrictBuilder {
+            congress: Some(self.congress),
+            state_code: Some(self.state_code),
+            district: Some(self.district),
+            current_member: Some(self.current_member),
+        }
+    }
 }
 
 impl Endpoint for CongressStateCodeDistrict {
@@ -43,10 +56,10 @@ impl Endpoint for CongressStateCodeDistrict {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
-        params.push_opt("current_member", self.current_member);
+        params.push_opt("currentMember", self.current_member);
 
         params
     }
@@ -54,7 +67,7 @@ impl Endpoint for CongressStateCodeDistrict {
 
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -70,19 +83,33 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("member/congress_state_code_district/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = CongressStateCodeDistrict::builder()
+            .congress(118_u16)
+            .state_code(CongressionalStateCode::MI)
+            .district(10_u16)
+            .build()
+            .unwrap();
+
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
 
+        assert!(res["members"].is_array());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = CongressStateCodeDistrict::builder()
             .congress(118_u16)
             .state_code(CongressionalStateCode::MI)
             .district(10_u16)
+            .current_member(true)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "member/congress/118/MI/10?currentMember=true"
+        );
     }
 }
@@ -1,8 +1,11 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::params::QueryParams};
+use crate::{api::endpoint::Endpoint, api::paged::Pageable, api::params::QueryParams};
 
 /// Represents the /member/:bioguideId/cosponsored-legislation endpoint.
 #[derive(Debug, Clone, Builder)]
@@ -20,6 +23,15 @@ impl<'a> CosponsoredLegislation<'a> {
     pub fn builder() -> CosponsoredLegislationBuilder<'a> {
         CosponsoredLegislationBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> CosponsoredLegislationBuilder<'a> {
+        CosponsoredLegislationBuilder {
+            bioguide_id: Some(self.bioguide_id.clone()),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+        }
+    }
 }
 
 impl Endpoint for CosponsoredLegislation<'_> {
@@ -31,7 +43,7 @@ impl Endpoint for CosponsoredLegislation<'_> {
         format!("member/{}/cosponsored-legislation", self.bioguide_id).into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
@@ -41,9 +53,11 @@ impl Endpoint for CosponsoredLegislation<'_> {
     }
 }
 
+impl Pageable for CosponsoredLegislation<'_> {}
+
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::paged::Page, api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -57,17 +71,30 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("member/cosponsored_legislation/endpoint");
+
+        let endpoint = CosponsoredLegislation::builder()
+            .bioguide_id("L000174")
+            .build()
+            .unwrap();
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
 
+        assert!(!page.items().is_empty());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = CosponsoredLegislation::builder()
             .bioguide_id("L000174")
+            .offset(20)
+            .limit(10)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "member/L000174/cosponsored-legislation?offset=20&limit=10"
+        );
     }
 }
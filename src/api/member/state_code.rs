@@ -1,15 +1,20 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::params::QueryParams};
+use crate::{
+    api::endpoint::Endpoint, api::member::CongressionalStateCode, api::params::QueryParams,
+};
 
 /// Represents the /member/:stateCode endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct StateCode {
     #[builder(setter(into))]
-    state_code: crate::api::member::CongressionalStateCode,
+    state_code: CongressionalStateCode,
     #[builder(default)]
     current_member: Option<bool>,
 }
@@ -18,6 +23,14 @@ impl StateCode {
     pub fn builder() -> StateCodeBuilder {
         StateCodeBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> StateCodeBuilder {
+        StateCodeBuilder {
+            state_code: Some(self.state_code),
+            current_member: Some(self.current_member),
+        }
+    }
 }
 
 impl Endpoint for StateCode {
@@ -29,10 +42,10 @@ impl Endpoint for StateCode {
         format!("member/{}", self.state_code.as_str()).into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
-        params.push_opt("current_member", self.current_member);
+        params.push_opt("currentMember", self.current_member);
 
         params
     }
@@ -40,7 +53,7 @@ impl Endpoint for StateCode {
 
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -54,17 +67,29 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("member/state_code/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = StateCode::builder()
+            .state_code(crate::api::member::CongressionalStateCode::VA)
+            .build()
+            .unwrap();
+
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
 
+        assert!(res["members"].is_array());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = StateCode::builder()
             .state_code(crate::api::member::CongressionalStateCode::VA)
+            .current_member(true)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "member/VA?currentMember=true"
+        );
     }
 }
@@ -1,10 +1,13 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::params::QueryParams};
-
-use super::CongressionalStateCode;
+use crate::{
+    api::endpoint::Endpoint, api::member::CongressionalStateCode, api::params::QueryParams,
+};
 
 /// Represents the /member/:stateCode/:district endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
@@ -22,6 +25,15 @@ impl StateCodeDistrict {
     pub fn builder() -> StateCodeDistrictBuilder {
         StateCodeDistrictBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> StateCodeDistrictBuilder {
+        StateCodeDistrictBuilder {
+            state_code: Some(self.state_code),
+            district: Some(self.district),
+            current_member: Some(self.current_member),
+        }
+    }
 }
 
 impl Endpoint for StateCodeDistrict {
@@ -33,10 +45,10 @@ impl Endpoint for StateCodeDistrict {
         format!("member/{}/{}", self.state_code.as_str(), self.district).into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
-        params.push_opt("current_member", self.current_member);
+        params.push_opt("currentMember", self.current_member);
 
         params
     }
@@ -44,7 +56,7 @@ impl Endpoint for StateCodeDistrict {
 
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -59,18 +71,31 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("member/state_code_district/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = StateCodeDistrict::builder()
+            .state_code(crate::api::member::CongressionalStateCode::VA)
+            .district(10_u16)
+            .build()
+            .unwrap();
+
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
 
+        assert!(res["members"].is_array());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = StateCodeDistrict::builder()
             .state_code(crate::api::member::CongressionalStateCode::VA)
             .district(10_u16)
+            .current_member(true)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "member/VA/10?currentMember=true"
+        );
     }
 }
@@ -1,15 +1,18 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::params::QueryParams};
+use crate::{api::endpoint::Endpoint, api::paged::Pageable, api::params::QueryParams};
 
 /// Represents the /nomination/:congress/:nominationNumber/:ordinal endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct Ordinal {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     nomination_number: u32,
     #[builder(setter(into))]
@@ -24,6 +27,17 @@ impl Ordinal {
     pub fn builder() -> OrdinalBuilder {
         OrdinalBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> OrdinalBuilder {
+        OrdinalBuilder {
+            congress: Some(self.congress),
+            nomination_number: Some(self.nomination_number),
+            ordinal: Some(self.ordinal),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+        }
+    }
 }
 
 impl Endpoint for Ordinal {
@@ -39,7 +53,7 @@ impl Endpoint for Ordinal {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
@@ -49,13 +63,11 @@ impl Endpoint for Ordinal {
     }
 }
 
+impl Pageable for Ordinal {}
+
 #[cfg(test)]
 mod tests {
-    use crate::{
-        api::{common::Format, query::Query},
-        auth::Auth,
-        cdg::Cdg,
-    };
+    use crate::{api::paged::Page, api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -145,11 +157,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_required_params_endpoint() {
-        dotenvy::dotenv().unwrap();
-
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let client = Cassette::new("nomination/ordinal/test_required_params_endpoint");
 
         let endpoint = Ordinal::builder()
             .congress(117_u8)
@@ -158,16 +166,14 @@ mod tests {
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
+
+        assert!(!page.items().is_empty());
     }
 
     #[tokio::test]
     async fn test_offset_endpoint() {
-        dotenvy::dotenv().unwrap();
-
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let client = Cassette::new("nomination/ordinal/test_offset_endpoint");
 
         let endpoint = Ordinal::builder()
             .congress(117_u8)
@@ -177,16 +183,14 @@ mod tests {
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
+
+        assert!(!page.items().is_empty());
     }
 
     #[tokio::test]
     async fn test_limit_endpoint() {
-        dotenvy::dotenv().unwrap();
-
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let client = Cassette::new("nomination/ordinal/test_limit_endpoint");
 
         let endpoint = Ordinal::builder()
             .congress(117_u8)
@@ -196,16 +200,14 @@ mod tests {
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
+
+        assert!(!page.items().is_empty());
     }
 
     #[tokio::test]
     async fn test_offset_limit_endpoint() {
-        dotenvy::dotenv().unwrap();
-
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let client = Cassette::new("nomination/ordinal/test_offset_limit_endpoint");
 
         let endpoint = Ordinal::builder()
             .congress(117_u8)
@@ -216,6 +218,25 @@ mod tests {
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
+
+        assert!(!page.items().is_empty());
+    }
+
+    #[test]
+    fn endpoint_url() {
+        let endpoint = Ordinal::builder()
+            .congress(117_u8)
+            .nomination_number(2467_u32)
+            .ordinal(1_u32)
+            .offset(20)
+            .limit(10)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "nomination/117/2467/1?offset=20&limit=10"
+        );
     }
 }
@@ -0,0 +1,554 @@
+//! Pagination support for list endpoints.
+//!
+//! Most congress.gov list endpoints accept `offset` and `limit` query parameters and wrap their
+//! results in an envelope with a `pagination` object describing the total `count` and the `next`
+//! page. The [`Paged`] wrapper follows that envelope so that callers do not need to hand-roll
+//! offset loops.
+
+use bytes::Bytes;
+use futures_util::stream::{self, Stream, TryStreamExt};
+use serde::{Deserialize, Deserializer, Serialize, de::DeserializeOwned, de::Error as _};
+use url::Url;
+
+use crate::api::{
+    cancel::{CancellationToken, cancellable},
+    client::Client,
+    common::Format,
+    endpoint::{Endpoint, request_url},
+    error::ApiError,
+    link::{Link, strip_api_key},
+    query,
+    query::Query,
+    response::{FromResponse, Response},
+    timeout::with_timeout,
+};
+
+/// A marker trait for endpoints which return paginated results.
+///
+/// Endpoints implementing this trait accept `offset` and `limit` parameters and respond with a
+/// `pagination` object alongside a single list of items.
+pub trait Pageable {}
+
+/// Limits on the number of items returned by a paged query.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Pagination {
+    /// Return every item available from the endpoint.
+    #[default]
+    All,
+    /// Return at most the given number of items.
+    Limit(usize),
+}
+
+impl Pagination {
+    fn max_items(self) -> usize {
+        match self {
+            Pagination::All => usize::MAX,
+            Pagination::Limit(limit) => limit,
+        }
+    }
+}
+
+/// The `pagination` object included in list responses.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaginationInfo {
+    /// The total number of items available.
+    #[serde(default)]
+    pub count: Option<u64>,
+    /// The URL of the next page of results.
+    #[serde(default)]
+    pub next: Option<Url>,
+    /// The URL of the previous page of results.
+    #[serde(default)]
+    pub prev: Option<Url>,
+}
+
+/// A single page of results from a [`Pageable`] endpoint.
+///
+/// The items are taken from the only list in the response body, e.g. `bills` for the `/bill`
+/// endpoint or `actions` for the `/bill/:congress/:billType/:billNumber/actions` endpoint. The
+/// page also keeps the URL it was requested from and the headers of the response.
+///
+/// A page is returned by querying an endpoint directly, or by [`Paged::pages`]. Either way, it is
+/// always requested as JSON.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    response: Response<Vec<T>>,
+    pagination: PaginationInfo,
+}
+
+impl<T> Page<T> {
+    /// The items contained in this page.
+    pub fn items(&self) -> &[T] {
+        self.response.body()
+    }
+
+    /// Consumes the page, returning its items.
+    pub fn into_items(self) -> Vec<T> {
+        self.response.into_body()
+    }
+
+    /// The response the page was decoded from, with the items as its body.
+    pub fn response(&self) -> &Response<Vec<T>> {
+        &self.response
+    }
+
+    /// The URL the page was requested from. Any API key is redacted.
+    pub fn url(&self) -> &Url {
+        self.response.url()
+    }
+
+    /// The requests left in the current hour, from the `X-RateLimit-Remaining` header.
+    pub fn ratelimit_remaining(&self) -> Option<u32> {
+        self.response.ratelimit_remaining()
+    }
+
+    /// The pagination information returned with this page.
+    pub fn pagination(&self) -> &PaginationInfo {
+        &self.pagination
+    }
+
+    /// The total number of items available from the endpoint, if reported.
+    pub fn count(&self) -> Option<u64> {
+        self.pagination.count
+    }
+
+    /// The URL of the next page, if any.
+    pub fn next(&self) -> Option<&Url> {
+        self.pagination.next.as_ref()
+    }
+
+    /// The URL of the previous page, if any.
+    pub fn prev(&self) -> Option<&Url> {
+        self.pagination.prev.as_ref()
+    }
+
+    /// The offset of the next page, if any.
+    pub fn next_offset(&self) -> Option<u64> {
+        offset(self.next()?)
+    }
+
+    /// The offset of the previous page, if any.
+    pub fn prev_offset(&self) -> Option<u64> {
+        offset(self.prev()?)
+    }
+}
+
+impl<T> FromResponse for Page<T>
+where
+    T: DeserializeOwned,
+{
+    fn format() -> Option<Format> {
+        Some(Format::Json)
+    }
+
+    fn from_response<E>(
+        format: Format,
+        url: &Url,
+        rsp: http::Response<Bytes>,
+    ) -> Result<Self, ApiError<E>>
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        let mut pagination = PaginationInfo::default();
+        let response = Response::<PageBody<T>>::from_response(format, url, rsp)?.map(|body| {
+            pagination = body.pagination;
+            body.items
+        });
+
+        Ok(Page {
+            response,
+            pagination,
+        })
+    }
+}
+
+fn offset(url: &Url) -> Option<u64> {
+    url.query_pairs()
+        .find(|(key, _)| key == "offset")
+        .map_or(Some(0), |(_, value)| value.parse().ok())
+}
+
+/// The body of a list response.
+struct PageBody<T> {
+    items: Vec<T>,
+    pagination: PaginationInfo,
+}
+
+impl<'de, T> Deserialize<'de> for PageBody<T>
+where
+    T: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut body = serde_json::Map::<String, serde_json::Value>::deserialize(deserializer)?;
+
+        let pagination = match body.remove("pagination") {
+            Some(value) => serde_json::from_value(value).map_err(D::Error::custom)?,
+            None => PaginationInfo::default(),
+        };
+        body.remove("request");
+
+        let mut lists = body.into_iter().filter_map(|(_, value)| match value {
+            serde_json::Value::Array(items) => Some(items),
+            _ => None,
+        });
+        let items = match (lists.next(), lists.next()) {
+            (Some(items), None) => items,
+            (None, _) => return Err(D::Error::custom("response does not contain a list")),
+            (Some(_), Some(_)) => {
+                return Err(D::Error::custom("response contains more than one list"));
+            }
+        };
+
+        let items = items
+            .into_iter()
+            .map(serde_json::from_value)
+            .collect::<Result<Vec<T>, _>>()
+            .map_err(D::Error::custom)?;
+
+        Ok(PageBody { items, pagination })
+    }
+}
+
+/// Wraps a [`Pageable`] endpoint so that every page of results may be fetched.
+///
+/// Pages are always requested as JSON, regardless of the client's format, as the
+/// `pagination` envelope is read from each response.
+#[derive(Debug, Clone)]
+pub struct Paged<E> {
+    endpoint: E,
+    pagination: Pagination,
+    cancellation: Option<CancellationToken>,
+}
+
+/// Collect data from a paged endpoint.
+pub fn paged<E>(endpoint: E, pagination: Pagination) -> Paged<E> {
+    Paged {
+        endpoint,
+        pagination,
+        cancellation: None,
+    }
+}
+
+impl<E> Paged<E> {
+    /// Stop fetching pages once the token is cancelled.
+    ///
+    /// The request in flight is dropped and the query fails with
+    /// [`ApiError::Cancelled`], after the pages already fetched.
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+}
+
+enum PageState {
+    Start,
+    Next(Url),
+    Done,
+}
+
+impl<E> Paged<E>
+where
+    E: Endpoint + Pageable,
+{
+    /// Stream each page of results from the endpoint.
+    ///
+    /// Pages are fetched lazily by following the `pagination.next` URL of the previous page.
+    /// When a [`Pagination::Limit`] is set, the final page is truncated to the limit. The
+    /// timeout of the endpoint or client applies to each page.
+    pub fn pages<'a, T, C>(
+        &'a self,
+        client: &'a C,
+    ) -> impl Stream<Item = Result<Page<T>, ApiError<C::Error>>> + 'a
+    where
+        T: DeserializeOwned + 'a,
+        C: Client,
+    {
+        let remaining = self.pagination.max_items();
+
+        stream::try_unfold(
+            (PageState::Start, remaining),
+            move |(state, remaining)| async move {
+                if remaining == 0 {
+                    return Ok(None);
+                }
+
+                let url = match state {
+                    PageState::Start => request_url(&self.endpoint, client, Some(Format::Json))?.0,
+                    PageState::Next(url) => next_page_url(client, url)?,
+                    PageState::Done => return Ok(None),
+                };
+
+                let endpoint = self.endpoint.endpoint();
+                let query = fetch_page(&self.endpoint, client, url);
+                let mut page: Page<T> =
+                    cancellable(self.cancellation.as_ref(), &endpoint, query).await?;
+
+                page.response.body_mut().truncate(remaining);
+                let remaining = remaining - page.items().len();
+
+                let next = match page.pagination.next.clone() {
+                    Some(next) if !page.items().is_empty() => PageState::Next(next),
+                    _ => PageState::Done,
+                };
+
+                Ok(Some((page, (next, remaining))))
+            },
+        )
+    }
+
+    /// Stream each item from the endpoint across all pages.
+    pub fn stream<'a, T, C>(
+        &'a self,
+        client: &'a C,
+    ) -> impl Stream<Item = Result<T, ApiError<C::Error>>> + 'a
+    where
+        T: DeserializeOwned + 'a,
+        C: Client,
+    {
+        self.pages(client)
+            .map_ok(|page| stream::iter(page.into_items().into_iter().map(Ok)))
+            .try_flatten()
+    }
+}
+
+/// The URL of the next page, on the client's base URL.
+///
+/// The API returns absolute `next` URLs, which would bypass a client pointed at a proxy, mirror or
+/// local server. Their path after `/v3/` is joined onto the client like a [`Link`]. URLs outside
+/// of the API are followed as they are.
+fn next_page_url<C>(client: &C, url: Url) -> Result<Url, ApiError<C::Error>>
+where
+    C: Client,
+{
+    match Link::try_from(url.clone()) {
+        Ok(link) => Ok(request_url(&link, client, Some(Format::Json))?.0),
+        Err(_) => Ok(authorize(client, url)),
+    }
+}
+
+/// Fetches a page of an endpoint, honoring the timeout of the endpoint or client.
+async fn fetch_page<E, T, C>(
+    endpoint: &E,
+    client: &C,
+    url: Url,
+) -> Result<Page<T>, ApiError<C::Error>>
+where
+    E: Endpoint,
+    T: DeserializeOwned,
+    C: Client,
+{
+    let query = query::query_url(client, endpoint.method(), url, Format::Json);
+    let timeout = endpoint.timeout().or(client.timeout());
+
+    with_timeout(timeout, &endpoint.endpoint(), query).await
+}
+
+/// Replaces any `api_key` already present in a URL with the client's auth.
+///
+/// The `next` and `prev` URLs returned by the API do not carry the key, but a URL captured from a
+/// previous request might.
+fn authorize<C>(client: &C, mut url: Url) -> Url
+where
+    C: Client,
+{
+    strip_api_key(&mut url);
+    client.set_auth(&mut url);
+    url
+}
+
+impl<E, T, C> Query<Vec<T>, C> for Paged<E>
+where
+    E: Endpoint + Pageable,
+    T: DeserializeOwned,
+    C: Client,
+{
+    async fn query(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+        self.stream(client).try_collect().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::TryStreamExt;
+    use serde::Deserialize;
+
+    use crate::{
+        api::{self, ApiError, Query, bill},
+        test_util::{MockClient, MockResponse},
+    };
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Bill {
+        number: String,
+    }
+
+    /// The page of six bills at `offset`, two at a time.
+    fn page(offset: u32) -> serde_json::Value {
+        let next = (offset < 4).then(|| {
+            format!(
+                "https://api.congress.gov/v3/bill/117?offset={}&limit=2&format=json",
+                offset + 2
+            )
+        });
+        serde_json::json!({
+            "bills": [
+                { "number": (offset + 1).to_string() },
+                { "number": (offset + 2).to_string() },
+            ],
+            "pagination": { "count": 6, "next": next },
+            "request": {},
+        })
+    }
+
+    fn client() -> MockClient {
+        let client = MockClient::new().with_api_key("KEY");
+        client.expect(
+            MockResponse::builder()
+                .endpoint("bill/117")
+                .body(page(0))
+                .build()
+                .unwrap(),
+        );
+        for offset in [2, 4] {
+            client.expect(
+                MockResponse::builder()
+                    .endpoint("bill/117")
+                    .add_query_param("offset", offset.to_string())
+                    .add_query_param("limit", "2")
+                    .body(page(offset))
+                    .build()
+                    .unwrap(),
+            );
+        }
+        client.expect(
+            MockResponse::builder()
+                .endpoint("bill/117")
+                .add_query_param("offset", "2")
+                .body(page(2))
+                .build()
+                .unwrap(),
+        );
+        client
+    }
+
+    #[tokio::test]
+    async fn follows_next() {
+        let client = client();
+        let endpoint = bill::Congress::builder().congress(117_u8).build().unwrap();
+
+        let bills: Vec<Bill> = api::paged(endpoint, Pagination::All)
+            .query(&client)
+            .await
+            .unwrap();
+
+        let numbers: Vec<_> = bills.iter().map(|bill| bill.number.as_str()).collect();
+        assert_eq!(numbers, ["1", "2", "3", "4", "5", "6"]);
+
+        let calls = client.calls();
+        assert_eq!(calls.len(), 3);
+        assert_eq!(
+            calls[1].query,
+            [
+                ("offset".into(), "2".into()),
+                ("limit".into(), "2".into()),
+                ("api_key".into(), "KEY".into()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn follows_next_on_client_base_url() {
+        let client = MockClient::new();
+        client
+            .expect(
+                MockResponse::builder()
+                    .endpoint("bill/117")
+                    .body(serde_json::json!({
+                        "bills": [{ "number": "1" }],
+                        "pagination": {
+                            "count": 2,
+                            "next": "https://mirror.example.org/v3/bill/117?offset=1&limit=1&format=xml",
+                        },
+                    }))
+                    .build()
+                    .unwrap(),
+            )
+            .expect(
+                MockResponse::builder()
+                    .endpoint("bill/117")
+                    .add_query_params([("offset", "1"), ("limit", "1")])
+                    .body(serde_json::json!({
+                        "bills": [{ "number": "2" }],
+                        "pagination": { "count": 2 },
+                    }))
+                    .build()
+                    .unwrap(),
+            );
+        let endpoint = bill::Congress::builder().congress(117_u8).build().unwrap();
+
+        let bills: Vec<Bill> = api::paged(endpoint, Pagination::All)
+            .query(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(bills.len(), 2);
+        assert_eq!(client.calls()[1].endpoint, "bill/117");
+    }
+
+    #[tokio::test]
+    async fn limit_stops_fetching() {
+        let client = client();
+        let endpoint = bill::Congress::builder().congress(117_u8).build().unwrap();
+        let paged = api::paged(endpoint, Pagination::Limit(3));
+
+        let pages: Vec<Page<Bill>> = paged.pages(&client).try_collect().await.unwrap();
+
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].count(), Some(6));
+        assert_eq!(pages[1].items(), [Bill { number: "3".into() }]);
+        assert_eq!(client.calls().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn cancellation_stops_fetching() {
+        let client = client();
+        let endpoint = bill::Congress::builder().congress(117_u8).build().unwrap();
+        let token = CancellationToken::new();
+        let paged = api::paged(endpoint, Pagination::All).with_cancellation(token.clone());
+
+        let mut pages = std::pin::pin!(paged.pages::<Bill, _>(&client));
+        assert!(pages.try_next().await.unwrap().is_some());
+        token.cancel();
+
+        let err = pages.try_next().await.unwrap_err();
+        assert!(matches!(err, ApiError::Cancelled { endpoint } if endpoint == "bill/117"));
+        assert!(pages.try_next().await.unwrap().is_none());
+        assert_eq!(client.calls().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn query_page() {
+        let client = client();
+        let endpoint = bill::Congress::builder()
+            .congress(117_u8)
+            .offset(2_u32)
+            .build()
+            .unwrap();
+
+        let page: Page<Bill> = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(page.items().len(), 2);
+        assert_eq!(page.count(), Some(6));
+        assert_eq!(page.next_offset(), Some(4));
+        assert_eq!(page.prev_offset(), None);
+        assert_eq!(
+            page.url().as_str(),
+            "https://api.congress.gov/v3/bill/117?offset=2&format=json&api_key=REDACTED"
+        );
+    }
+}
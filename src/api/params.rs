@@ -99,8 +99,10 @@ impl ParamValue<'static> for Format {
 }
 
 impl ParamValue<'static> for Sort {
+    /// The API sorts by update date and expects e.g. `sort=updateDate+desc`,
+    /// where the `+` is the form encoding of a space.
     fn as_value(&self) -> Cow<'static, str> {
-        self.as_str().into()
+        format!("updateDate {}", self.as_str()).into()
     }
 }
 
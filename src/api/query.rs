@@ -1,10 +1,176 @@
-use crate::{api::client::Client, api::error::ApiError, cdg::CdgError};
-use http::Uri;
+#[cfg(feature = "tracing")]
+use crate::api::error::redact_url;
+use crate::{
+    api::client::Client, api::common::Format, api::error::ApiError, api::response::FromResponse,
+    retry::Attempts,
+};
+use bytes::Bytes;
+use http::{Method, Request, Response, Uri, header};
+use serde::de::DeserializeOwned;
 use url::Url;
 
 /// A helper function for parsing a URL to an HTTP URI.
-pub fn url_to_http_uri(url: Url) -> Result<Uri, CdgError> {
-    Ok(url.as_str().parse::<Uri>()?)
+pub fn url_to_http_uri(url: Url) -> Result<Uri, http::uri::InvalidUri> {
+    url.as_str().parse::<Uri>()
+}
+
+/// Performs a request against a fully constructed URL and returns the
+/// response untouched.
+///
+/// The URL is expected to already carry its query parameters, including
+/// `format`, and auth.
+pub(crate) async fn raw_query_url<C>(
+    client: &C,
+    method: Method,
+    url: Url,
+    format: Format,
+) -> Result<Response<Bytes>, ApiError<C::Error>>
+where
+    C: Client,
+{
+    let req = Request::builder()
+        .method(method)
+        .uri(url_to_http_uri(url)?)
+        .header(header::ACCEPT, format.as_header()?);
+
+    #[cfg(feature = "tracing")]
+    return rest_traced(client, req).await;
+    #[cfg(not(feature = "tracing"))]
+    client.rest(req, Vec::new()).await
+}
+
+/// Performs the request within a span describing the query and its
+/// response. The API key is redacted from the recorded parameters.
+#[cfg(feature = "tracing")]
+async fn rest_traced<C>(
+    client: &C,
+    req: http::request::Builder,
+) -> Result<Response<Bytes>, ApiError<C::Error>>
+where
+    C: Client,
+{
+    use tracing::{Instrument, field::Empty};
+
+    use crate::rate_limit::{RATE_LIMIT_LIMIT_HEADER, RATE_LIMIT_REMAINING_HEADER};
+
+    let uri = req.uri_ref().map(ToString::to_string).unwrap_or_default();
+    let redacted = redact_url(&Url::parse(&uri)?);
+    let span = tracing::info_span!(
+        "query",
+        method = %req.method_ref().cloned().unwrap_or_default(),
+        endpoint = redacted.path(),
+        params = redacted.query().unwrap_or_default(),
+        status = Empty,
+        size = Empty,
+        latency_ms = Empty,
+        attempts = Empty,
+        key = Empty,
+        ratelimit_limit = Empty,
+        ratelimit_remaining = Empty,
+    );
+
+    let start = std::time::Instant::now();
+    let rsp = client.rest(req, Vec::new()).instrument(span.clone()).await;
+    span.record("latency_ms", start.elapsed().as_millis() as u64);
+
+    match &rsp {
+        Ok(rsp) => {
+            span.record("status", rsp.status().as_u16());
+            span.record("size", rsp.body().len());
+            if let Some(id) = rsp.extensions().get::<crate::auth::KeyId>() {
+                span.record("key", id.0);
+            }
+            let header = |name| rsp.headers().get(name).and_then(|v| v.to_str().ok());
+            if let Some(limit) = header(RATE_LIMIT_LIMIT_HEADER) {
+                span.record("ratelimit_limit", limit);
+            }
+            if let Some(remaining) = header(RATE_LIMIT_REMAINING_HEADER) {
+                span.record("ratelimit_remaining", remaining);
+            }
+            if rsp.status().is_success() {
+                tracing::debug!(parent: &span, "request completed");
+            } else {
+                tracing::warn!(parent: &span, status = rsp.status().as_u16(), "request failed");
+            }
+        }
+        Err(err) => tracing::error!(parent: &span, error = %err, "request failed"),
+    }
+
+    rsp
+}
+
+/// Performs a request against a fully constructed URL and decodes the
+/// response in the given format.
+pub(crate) async fn query_url<T, C>(
+    client: &C,
+    method: Method,
+    url: Url,
+    format: Format,
+) -> Result<T, ApiError<C::Error>>
+where
+    T: FromResponse,
+    C: Client,
+{
+    let rsp = raw_query_url(client, method, url.clone(), format).await?;
+
+    let res = T::from_response(format, &url, rsp);
+    #[cfg(feature = "tracing")]
+    if let Err(err) = &res {
+        tracing::error!(url = %redact_url(&url), error = %err, "query failed");
+    }
+    res
+}
+
+/// Deserializes a response body in the given format, turning unsuccessful
+/// statuses into structured errors.
+pub(crate) fn decode<T, E>(
+    format: Format,
+    url: &Url,
+    rsp: Response<Bytes>,
+) -> Result<T, ApiError<E>>
+where
+    T: DeserializeOwned,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let status = rsp.status();
+    let attempts = rsp.extensions().get::<Attempts>().copied();
+    let body = rsp.into_body();
+
+    if !status.is_success() {
+        let err = ApiError::from_response(url, status, body);
+        return Err(match attempts {
+            Some(Attempts(attempts)) if attempts > 1 => ApiError::Retry {
+                attempts,
+                source: Box::new(err),
+            },
+            _ => err,
+        });
+    }
+
+    match format {
+        Format::Json => serde_json::from_slice(&body).map_err(|e| ApiError::DataType { source: e }),
+        Format::Xml => decode_xml(&body),
+    }
+}
+
+#[cfg(feature = "xml")]
+fn decode_xml<T, E>(body: &[u8]) -> Result<T, ApiError<E>>
+where
+    T: DeserializeOwned,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    quick_xml::de::from_reader(body).map_err(|e| ApiError::Xml { source: e })
+}
+
+#[cfg(not(feature = "xml"))]
+fn decode_xml<T, E>(_body: &[u8]) -> Result<T, ApiError<E>>
+where
+    T: DeserializeOwned,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    Err(ApiError::UnsupportedFormat {
+        format: Format::Xml,
+    })
 }
 
 /// A trait which represents a query which may be made to a congress.gov client.
@@ -16,3 +182,252 @@ where
     fn query(&self, client: &C)
     -> impl std::future::Future<Output = Result<T, ApiError<C::Error>>>;
 }
+
+/// A trait which represents a query returning the raw response from a
+/// congress.gov client.
+///
+/// The status, headers and body of the response are returned untouched,
+/// including for error statuses, so the body may be archived or handed to
+/// another parser.
+pub trait RawQuery<C>
+where
+    C: Client,
+{
+    /// Perform the query against the client, returning the raw response.
+    fn raw_query(
+        &self,
+        client: &C,
+    ) -> impl std::future::Future<Output = Result<Response<Bytes>, ApiError<C::Error>>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use serde::Deserialize;
+
+    use crate::{
+        api::congress,
+        test_util::{MockClient, MockError, MockResponse},
+    };
+
+    use super::*;
+
+    fn raw_client() -> MockClient {
+        let client = MockClient::new().with_api_key("KEY");
+        client.expect(
+            MockResponse::builder()
+                .endpoint("congress/current")
+                .add_query_param("limit", "5")
+                .status(StatusCode::NOT_FOUND)
+                .header("x-ratelimit-remaining", "4999")
+                .raw_body(Bytes::from_static(b"not json"))
+                .build()
+                .unwrap(),
+        );
+        client
+    }
+
+    #[tokio::test]
+    async fn raw_query_is_untouched() {
+        let client = raw_client();
+        let endpoint = congress::CurrentCongress::builder()
+            .limit(5_u8)
+            .build()
+            .unwrap();
+
+        let rsp = endpoint.raw_query(&client).await.unwrap();
+
+        assert_eq!(rsp.status(), StatusCode::NOT_FOUND);
+        assert_eq!(rsp.headers()["x-ratelimit-remaining"], "4999");
+        assert_eq!(rsp.body().as_ref(), b"not json");
+        assert_eq!(
+            client.calls()[0].query,
+            [
+                ("limit".into(), "5".into()),
+                ("api_key".into(), "KEY".into()),
+            ]
+        );
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn query_is_traced() {
+        use std::{
+            io,
+            sync::{Arc, Mutex},
+        };
+
+        #[derive(Clone, Default)]
+        struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+        impl io::Write for Buffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(move || writer.clone())
+            .with_span_events(tracing_subscriber::fmt::format::FmtSpan::CLOSE)
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let client = raw_client();
+        let endpoint = congress::CurrentCongress::builder()
+            .limit(5_u8)
+            .build()
+            .unwrap();
+        endpoint.raw_query(&client).await.unwrap();
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(
+            output.contains("endpoint=\"/v3/congress/current\""),
+            "{}",
+            output
+        );
+        assert!(output.contains("api_key=REDACTED"), "{}", output);
+        assert!(!output.contains("api_key=KEY"), "{}", output);
+        assert!(output.contains("status=404"), "{}", output);
+        assert!(output.contains("size=8"), "{}", output);
+        assert!(
+            output.contains("ratelimit_remaining=\"4999\""),
+            "{}",
+            output
+        );
+        assert!(output.contains("request failed"), "{}", output);
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Congress {
+        name: String,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct CurrentCongress {
+        congress: Congress,
+    }
+
+    fn url() -> Url {
+        Url::parse("https://api.congress.gov/v3/congress/current?format=json&api_key=SECRET")
+            .unwrap()
+    }
+
+    fn response(status: StatusCode, body: &'static [u8]) -> Response<Bytes> {
+        Response::builder()
+            .status(status)
+            .body(Bytes::from_static(body))
+            .unwrap()
+    }
+
+    #[test]
+    fn decode_json() {
+        let rsp = response(StatusCode::OK, br#"{"congress":{"name":"118th Congress"}}"#);
+
+        let res: CurrentCongress = decode::<_, MockError>(Format::Json, &url(), rsp).unwrap();
+
+        assert_eq!(res.congress.name, "118th Congress");
+    }
+
+    #[test]
+    fn decode_invalid_json() {
+        let rsp = response(StatusCode::OK, b"<html></html>");
+
+        let err = decode::<CurrentCongress, MockError>(Format::Json, &url(), rsp).unwrap_err();
+
+        assert!(matches!(err, ApiError::DataType { .. }));
+    }
+
+    #[test]
+    fn decode_not_found() {
+        let rsp = response(
+            StatusCode::NOT_FOUND,
+            br#"{"error":"Unknown resource: congress/current","request":{}}"#,
+        );
+
+        let err = decode::<CurrentCongress, MockError>(Format::Json, &url(), rsp).unwrap_err();
+
+        let ApiError::NotFound { response } = &err else {
+            panic!("unexpected error: {:?}", err);
+        };
+        assert_eq!(
+            response.message.as_deref(),
+            Some("Unknown resource: congress/current")
+        );
+        assert_eq!(
+            response.url.as_str(),
+            "https://api.congress.gov/v3/congress/current?format=json&api_key=REDACTED"
+        );
+        assert!(!err.to_string().contains("SECRET"));
+    }
+
+    #[test]
+    fn decode_invalid_api_key() {
+        let rsp = response(
+            StatusCode::FORBIDDEN,
+            br#"{"error":{"code":"API_KEY_INVALID","message":"An invalid api_key was supplied."}}"#,
+        );
+
+        let err = decode::<CurrentCongress, MockError>(Format::Json, &url(), rsp).unwrap_err();
+
+        let ApiError::InvalidApiKey { response } = err else {
+            panic!("unexpected error: {:?}", err);
+        };
+        assert_eq!(response.code.as_deref(), Some("API_KEY_INVALID"));
+        assert_eq!(
+            response.message.as_deref(),
+            Some("An invalid api_key was supplied.")
+        );
+    }
+
+    #[test]
+    fn decode_unknown_error_shape() {
+        let rsp = response(StatusCode::BAD_GATEWAY, b"<html>Bad Gateway</html>");
+
+        let err = decode::<CurrentCongress, MockError>(Format::Json, &url(), rsp).unwrap_err();
+
+        let ApiError::Server { response } = err else {
+            panic!("unexpected error: {:?}", err);
+        };
+        assert_eq!(response.message, None);
+        assert_eq!(response.body.as_ref(), b"<html>Bad Gateway</html>");
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn decode_xml() {
+        let rsp = response(
+            StatusCode::OK,
+            b"<?xml version='1.0' encoding='utf-8'?>\n<api-root><congress><name>118th Congress</name></congress></api-root>",
+        );
+
+        let res: CurrentCongress = decode::<_, MockError>(Format::Xml, &url(), rsp).unwrap();
+
+        assert_eq!(res.congress.name, "118th Congress");
+    }
+
+    #[cfg(not(feature = "xml"))]
+    #[test]
+    fn decode_xml_unsupported() {
+        let err = decode::<CurrentCongress, MockError>(
+            Format::Xml,
+            &url(),
+            response(StatusCode::OK, b""),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ApiError::UnsupportedFormat {
+                format: Format::Xml
+            }
+        ));
+    }
+}
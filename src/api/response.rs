@@ -0,0 +1,231 @@
+//! Query results along with the response they were decoded from.
+
+use std::error::Error;
+
+use bytes::Bytes;
+use http::{HeaderMap, StatusCode};
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use crate::{
+    api::{
+        common::Format,
+        error::{ApiError, redact_url},
+        query,
+    },
+    rate_limit::{RATE_LIMIT_LIMIT_HEADER, RATE_LIMIT_REMAINING_HEADER},
+};
+
+/// A decoded response body along with the URL it was requested from and the headers of the
+/// response.
+///
+/// Any endpoint may be queried into a `Response`:
+///
+/// ```rust,no_run
+/// # use congressdotgov_rs::api::{self, Query, bill};
+/// # use congressdotgov_rs::{Auth, Cdg};
+/// # tokio_test::block_on(async {
+/// # let client = Cdg::builder().auth(Auth::Token("API_KEY".into())).build().unwrap();
+/// let endpoint = bill::Bill::builder().build().unwrap();
+/// let rsp: api::Response<serde_json::Value> = endpoint.query(&client).await.unwrap();
+///
+/// println!("{} requests left", rsp.ratelimit_remaining().unwrap_or_default());
+/// # })
+/// ```
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    body: T,
+    url: Url,
+    status: StatusCode,
+    headers: HeaderMap,
+}
+
+impl<T> Response<T> {
+    /// The decoded body.
+    pub fn body(&self) -> &T {
+        &self.body
+    }
+
+    /// The decoded body.
+    pub fn body_mut(&mut self) -> &mut T {
+        &mut self.body
+    }
+
+    /// Consumes the response, returning its body.
+    pub fn into_body(self) -> T {
+        self.body
+    }
+
+    /// Transforms the body, keeping the rest of the response.
+    pub fn map<U, F>(self, f: F) -> Response<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        Response {
+            body: f(self.body),
+            url: self.url,
+            status: self.status,
+            headers: self.headers,
+        }
+    }
+
+    /// The URL requested, including its query parameters. Any API key is redacted.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// The status of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The headers of the response.
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// The hourly request quota of the API key, from the `X-RateLimit-Limit` header.
+    pub fn ratelimit_limit(&self) -> Option<u32> {
+        self.header(RATE_LIMIT_LIMIT_HEADER)
+    }
+
+    /// The requests left in the current hour, from the `X-RateLimit-Remaining` header.
+    pub fn ratelimit_remaining(&self) -> Option<u32> {
+        self.header(RATE_LIMIT_REMAINING_HEADER)
+    }
+
+    fn header(&self, name: &str) -> Option<u32> {
+        self.headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse().ok())
+    }
+}
+
+/// Types the response to a query can be decoded into.
+///
+/// This is implemented for every deserializable type, which is decoded from the response body
+/// alone, as well as for [`Response`] and [`Page`](crate::api::Page), which also keep the URL and
+/// headers of the response.
+pub trait FromResponse: Sized {
+    /// The format the response must be requested in, overriding the format of the endpoint and
+    /// client.
+    fn format() -> Option<Format> {
+        None
+    }
+
+    /// Decodes the response to a request for the given URL.
+    fn from_response<E>(
+        format: Format,
+        url: &Url,
+        rsp: http::Response<Bytes>,
+    ) -> Result<Self, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static;
+}
+
+impl<T> FromResponse for T
+where
+    T: DeserializeOwned,
+{
+    fn from_response<E>(
+        format: Format,
+        url: &Url,
+        rsp: http::Response<Bytes>,
+    ) -> Result<Self, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        query::decode(format, url, rsp)
+    }
+}
+
+impl<T> FromResponse for Response<T>
+where
+    T: DeserializeOwned,
+{
+    fn from_response<E>(
+        format: Format,
+        url: &Url,
+        rsp: http::Response<Bytes>,
+    ) -> Result<Self, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        let status = rsp.status();
+        let headers = rsp.headers().clone();
+        let body = query::decode(format, url, rsp)?;
+
+        Ok(Response {
+            body,
+            url: redact_url(url),
+            status,
+            headers,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::{
+        api::{self, Query, bill},
+        test_util::{MockClient, MockError, MockResponse},
+    };
+
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Bills {
+        bills: Vec<serde_json::Value>,
+    }
+
+    #[tokio::test]
+    async fn keeps_url() {
+        let client = MockClient::new();
+        client.expect(
+            MockResponse::builder()
+                .endpoint("bill/117")
+                .add_query_param("limit", "1")
+                .body(serde_json::json!({ "bills": [{}] }))
+                .build()
+                .unwrap(),
+        );
+        let endpoint = bill::Congress::builder()
+            .congress(117_u8)
+            .limit(1_u8)
+            .build()
+            .unwrap();
+
+        let rsp: api::Response<Bills> = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(rsp.body().bills.len(), 1);
+        assert_eq!(rsp.status(), StatusCode::OK);
+        assert_eq!(
+            rsp.url().as_str(),
+            "https://api.congress.gov/v3/bill/117?limit=1&format=json"
+        );
+        assert_eq!(rsp.ratelimit_remaining(), None);
+    }
+
+    #[test]
+    fn rate_limit_headers() {
+        let url = Url::parse("https://api.congress.gov/v3/bill?api_key=SECRET").unwrap();
+        let rsp = http::Response::builder()
+            .header(RATE_LIMIT_LIMIT_HEADER, "5000")
+            .header(RATE_LIMIT_REMAINING_HEADER, " 4998")
+            .body(Bytes::from_static(b"{}"))
+            .unwrap();
+
+        let rsp: Response<serde_json::Value> =
+            Response::from_response::<MockError>(Format::Json, &url, rsp).unwrap();
+
+        assert_eq!(rsp.ratelimit_limit(), Some(5000));
+        assert_eq!(rsp.ratelimit_remaining(), Some(4998));
+        assert_eq!(
+            rsp.url().as_str(),
+            "https://api.congress.gov/v3/bill?api_key=REDACTED"
+        );
+    }
+}
@@ -0,0 +1,398 @@
+//! Parsing API URLs back into endpoints.
+//!
+//! A [`Route`] is the endpoint a URL of the API was built from, along with its query parameters.
+//! It can be queried again as is, or turned back into a builder to change some parameters:
+//!
+//! ```rust
+//! # use congressdotgov_rs::api::Route;
+//! let route: Route = "https://api.congress.gov/v3/bill/117/hr/3076/actions?offset=20&format=json"
+//!     .parse()
+//!     .unwrap();
+//!
+//! let Route::BillActions(actions) = route else {
+//!     panic!("not the actions of a bill");
+//! };
+//! let next = actions.to_builder().offset(40_u32).build().unwrap();
+//! ```
+
+use chrono::{DateTime, Utc};
+use http::Method;
+use std::{borrow::Cow, str::FromStr, time::Duration};
+use thiserror::Error;
+use url::Url;
+
+use crate::api::{
+    amendments::CongressionalAmendmentType,
+    committee,
+    committee_report::CommitteeReportType,
+    common::{BillType, CommitteeChamber, Format, Sort},
+    endpoint::{Endpoint, UrlBase},
+    house_communication::HouseCommunicationType,
+    law::CongressionalLawType,
+    link::{Link, LinkError},
+    member::CongressionalStateCode,
+    params::QueryParams,
+    senate_communication::SenateCommunicationType,
+};
+
+pub use self::routes::Route;
+
+mod routes;
+
+/// Errors which may occur when parsing a [`Route`].
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum RouteError {
+    #[error("{}", source)]
+    Link {
+        #[from]
+        source: LinkError,
+    },
+    #[error("no endpoint matches {}", path)]
+    UnknownPath { path: String },
+    #[error("invalid value for {}: {}", name, value)]
+    InvalidParam { name: String, value: String },
+    #[error("unexpected query parameter {}", name)]
+    UnexpectedParam { name: String },
+    #[error("{} does not respond with a list", path)]
+    NotPageable { path: String },
+}
+
+impl Route {
+    /// Parse the endpoint a URL of the API was built from.
+    ///
+    /// The `format` and `api_key` parameters are ignored, as they are set by the client. Any
+    /// other parameter the endpoint does not accept is an error.
+    pub fn from_url(url: &Url) -> Result<Self, RouteError> {
+        Link::try_from(url.clone())?.try_into()
+    }
+}
+
+impl TryFrom<&Link> for Route {
+    type Error = RouteError;
+
+    fn try_from(link: &Link) -> Result<Self, Self::Error> {
+        let segments: Vec<&str> = link.path().split('/').collect();
+        let mut params = Params::new(link.url());
+
+        let route = Route::from_segments(&segments, &mut params)?;
+        params.finish()?;
+
+        Ok(route)
+    }
+}
+
+impl TryFrom<Link> for Route {
+    type Error = RouteError;
+
+    fn try_from(link: Link) -> Result<Self, Self::Error> {
+        Route::try_from(&link)
+    }
+}
+
+impl TryFrom<Url> for Route {
+    type Error = RouteError;
+
+    fn try_from(url: Url) -> Result<Self, Self::Error> {
+        Link::try_from(url)?.try_into()
+    }
+}
+
+impl FromStr for Route {
+    type Err = RouteError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<Link>()?.try_into()
+    }
+}
+
+impl Endpoint for Route {
+    fn method(&self) -> Method {
+        self.as_endpoint().method()
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.as_endpoint().endpoint()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        self.as_endpoint().url_base()
+    }
+
+    fn format(&self) -> Option<Format> {
+        self.as_endpoint().format()
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.as_endpoint().timeout()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        self.as_endpoint().parameters()
+    }
+}
+
+/// The query parameters of a URL which are yet to be parsed.
+pub(crate) struct Params {
+    pairs: Vec<(String, String)>,
+}
+
+impl Params {
+    fn new(url: &Url) -> Self {
+        Params {
+            pairs: url
+                .query_pairs()
+                .filter(|(key, _)| key != "format" && key != "api_key")
+                .map(|(key, value)| (key.into_owned(), value.into_owned()))
+                .collect(),
+        }
+    }
+
+    /// Parse and remove a parameter.
+    pub(crate) fn take<T>(&mut self, name: &str) -> Result<Option<T>, RouteError>
+    where
+        T: FromParam,
+    {
+        let Some(index) = self.pairs.iter().position(|(key, _)| key == name) else {
+            return Ok(None);
+        };
+        let (name, value) = self.pairs.remove(index);
+
+        match T::from_param(&value) {
+            Some(value) => Ok(Some(value)),
+            None => Err(RouteError::InvalidParam { name, value }),
+        }
+    }
+
+    /// Fail if any parameter was not taken by the endpoint.
+    fn finish(self) -> Result<(), RouteError> {
+        match self.pairs.into_iter().next() {
+            Some((name, _)) => Err(RouteError::UnexpectedParam { name }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Parse a path segment.
+pub(crate) fn segment<T>(value: &str) -> Option<T>
+where
+    T: FromParam,
+{
+    T::from_param(value)
+}
+
+/// Values of path segments and query parameters.
+pub(crate) trait FromParam: Sized {
+    /// Parse the value as it appears in a URL.
+    fn from_param(value: &str) -> Option<Self>;
+}
+
+macro_rules! impl_from_param_parse {
+    ($($ty:ty),*) => {
+        $(
+            impl FromParam for $ty {
+                fn from_param(value: &str) -> Option<Self> {
+                    value.parse().ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_param_parse!(u8, u16, u32, bool);
+
+impl FromParam for Cow<'static, str> {
+    fn from_param(value: &str) -> Option<Self> {
+        (!value.is_empty()).then(|| value.to_string().into())
+    }
+}
+
+impl FromParam for DateTime<Utc> {
+    fn from_param(value: &str) -> Option<Self> {
+        DateTime::parse_from_rfc3339(value)
+            .ok()
+            .map(|date| date.with_timezone(&Utc))
+    }
+}
+
+impl FromParam for Sort {
+    /// The API sorts by update date, e.g. `sort=updateDate+desc`.
+    fn from_param(value: &str) -> Option<Self> {
+        let value = value.strip_prefix("updateDate ").unwrap_or(value);
+        [Sort::Asc, Sort::Desc]
+            .into_iter()
+            .find(|sort| sort.as_str() == value)
+    }
+}
+
+/// Parse enums by comparing the value with the `as_str` of each variant.
+macro_rules! impl_from_param_variants {
+    ($($ty:ty => [$($variant:ident),* $(,)?]),* $(,)?) => {
+        $(
+            impl FromParam for $ty {
+                fn from_param(value: &str) -> Option<Self> {
+                    [$(<$ty>::$variant),*]
+                        .into_iter()
+                        .find(|variant| variant.as_str().eq_ignore_ascii_case(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_param_variants! {
+    BillType => [Hr, S, Hjres, Sjres, Hconres, Sconres, Hres, Sres],
+    CommitteeChamber => [House, Senate, NoChamber],
+    committee::CommitteeChamber => [House, Senate, Joint],
+    CongressionalAmendmentType => [Hamdt, Samdt, Suamdt],
+    CommitteeReportType => [Hrpt, Srpt, Erpt],
+    HouseCommunicationType => [Ec, Ml, Pm, Pt],
+    SenateCommunicationType => [Ec, Pm, Pom],
+    CongressionalLawType => [Public, Private],
+    CongressionalStateCode => [
+        AL, AK, AZ, AR, CA, CO, CT, DE, DC, FL, GA, HI, ID, IL, IN, IA, KS, KY, LA, ME, MD, MA, MI,
+        MN, MS, MO, MT, NE, NV, NH, NJ, NM, NY, NC, ND, OH, OK, OR, PA, RI, SC, SD, TN, TX, UT, VT,
+        VA, WA, WV, WI, WY,
+    ],
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use crate::api::member;
+
+    use super::*;
+
+    fn route(url: &str) -> Route {
+        url.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_path_and_params() {
+        let route = route(
+            "https://api.congress.gov/v3/bill/117/hr/3076/actions?offset=20&limit=250&format=json&api_key=SECRET",
+        );
+
+        assert!(matches!(route, Route::BillActions(_)));
+        assert_eq!(route.endpoint(), "bill/117/hr/3076/actions");
+
+        let mut url = Url::parse("https://api.congress.gov/v3/").unwrap();
+        route.parameters().add_to_url(&mut url);
+        assert_eq!(url.query(), Some("offset=20&limit=250"));
+    }
+
+    #[test]
+    fn parses_dates_and_sort() {
+        let Route::Bill(bill) = route(
+            "https://api.congress.gov/v3/bill?fromDateTime=2022-04-01T00:00:00Z&sort=updateDate+desc",
+        ) else {
+            panic!("not the bill endpoint");
+        };
+        let bill = bill.to_builder().offset(10_u32).build().unwrap();
+
+        let mut url = Url::parse("https://api.congress.gov/v3/").unwrap();
+        bill.parameters().add_to_url(&mut url);
+        assert_eq!(
+            url.query(),
+            Some("offset=10&fromDateTime=2022-04-01T00%3A00%3A00Z&sort=updateDate+desc")
+        );
+        assert_eq!(
+            DateTime::<Utc>::from_param("2022-04-01T00:00:00Z"),
+            Some(Utc.with_ymd_and_hms(2022, 4, 1, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn prefers_typed_segments() {
+        assert!(matches!(
+            route("https://api.congress.gov/v3/committee/house"),
+            Route::CommitteeChamber(_)
+        ));
+        assert!(matches!(
+            route("https://api.congress.gov/v3/committee/118"),
+            Route::CommitteeCongress(_)
+        ));
+        assert!(matches!(
+            route("https://api.congress.gov/v3/committee/118/house"),
+            Route::CommitteeChamberByCongress(_)
+        ));
+        assert!(matches!(
+            route("https://api.congress.gov/v3/committee/house/hsag00"),
+            Route::CommitteeCode(_)
+        ));
+        assert!(matches!(
+            route("https://api.congress.gov/v3/member/congress/118"),
+            Route::MemberCongress(_)
+        ));
+        assert!(matches!(
+            route("https://api.congress.gov/v3/member/MI/10"),
+            Route::MemberStateCodeDistrict(_)
+        ));
+        assert!(matches!(
+            route("https://api.congress.gov/v3/member/L000174"),
+            Route::MemberBioguideId(_)
+        ));
+        assert!(matches!(
+            route("https://api.congress.gov/v3/congress/current"),
+            Route::CurrentCongress(_)
+        ));
+    }
+
+    #[test]
+    fn round_trips_endpoints() {
+        let endpoint = member::CongressStateCodeDistrict::builder()
+            .congress(118_u16)
+            .state_code(CongressionalStateCode::MI)
+            .district(10_u16)
+            .current_member(true)
+            .build()
+            .unwrap();
+        let bills = committee::Bills::builder()
+            .chamber(committee::CommitteeChamber::Joint)
+            .committee_code("jslc00")
+            .from_date_time(Utc.with_ymd_and_hms(2023, 1, 3, 12, 30, 0).unwrap())
+            .limit(5_u8)
+            .build()
+            .unwrap();
+
+        for endpoint in [&endpoint as &dyn Endpoint, &bills] {
+            let mut url = Url::parse("https://api.congress.gov/v3/")
+                .unwrap()
+                .join(&endpoint.endpoint())
+                .unwrap();
+            endpoint.parameters().add_to_url(&mut url);
+
+            let route = Route::from_url(&url).unwrap();
+
+            assert_eq!(route.endpoint(), endpoint.endpoint());
+            let mut parsed = Url::parse("https://api.congress.gov/v3/").unwrap();
+            route.parameters().add_to_url(&mut parsed);
+            assert_eq!(parsed.query(), url.query());
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let err = "https://api.congress.gov/v3/bill/117/hx"
+            .parse::<Route>()
+            .unwrap_err();
+        assert!(matches!(err, RouteError::UnknownPath { .. }));
+        assert_eq!(err.to_string(), "no endpoint matches bill/117/hx");
+
+        let err = "https://api.congress.gov/v3/bill?limit=many"
+            .parse::<Route>()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "invalid value for limit: many");
+
+        let err = "https://api.congress.gov/v3/bill/117/hr/3076?offset=1"
+            .parse::<Route>()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "unexpected query parameter offset");
+
+        assert!(matches!(
+            "https://www.congress.gov/bill/117th-congress/house-bill/3076".parse::<Route>(),
+            Err(RouteError::Link { .. })
+        ));
+    }
+}
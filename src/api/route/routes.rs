@@ -0,0 +1,2367 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
+use chrono::{DateTime, Utc};
+use std::borrow::Cow;
+
+use crate::api::{
+    amendments, bill, bound_congressional_record, committee, committee_meeting, committee_print,
+    committee_report, congress, congressional_record, crsreport, daily_congressional_record,
+    hearing, house_communication, house_requirement, law, member, nomination, senate_communication,
+    summaries, treaty,
+};
+use crate::api::{
+    endpoint::Endpoint,
+    route::{Params, RouteError, segment},
+};
+
+/// An endpoint of the API, as parsed from its URL.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum Route {
+    /// The /amendment endpoint.
+    Amendment(amendments::Amendment),
+    /// The /amendment/:congress endpoint.
+    AmendmentCongress(amendments::Congress),
+    /// The /amendment/:congress/:amendmentType endpoint.
+    AmendmentType(amendments::AmendmentType),
+    /// The /amendment/:congress/:amendmentType/:amendmentNumber endpoint.
+    AmendmentNumber(amendments::AmendmentNumber),
+    /// The /amendment/:congress/:amendmentType/:amendmentNumber/actions endpoint.
+    AmendmentActions(amendments::Actions),
+    /// The /amendment/:congress/:amendmentType/:amendmentNumber/amendments endpoint.
+    AmendmentAmendments(amendments::Amendments),
+    /// The /amendment/:congress/:amendmentType/:amendmentNumber/cosponsors endpoint.
+    AmendmentCosponsors(amendments::Cosponsors),
+    /// The /amendment/:congress/:amendmentType/:amendmentNumber/text endpoint.
+    AmendmentText(amendments::Text),
+    /// The /bill endpoint.
+    Bill(bill::Bill),
+    /// The /bill/:congress endpoint.
+    BillCongress(bill::Congress),
+    /// The /bill/:congress/:billType endpoint.
+    BillType(bill::BillType),
+    /// The /bill/:congress/:billType/:billNumber endpoint.
+    BillNumber(bill::BillNumber),
+    /// The /bill/:congress/:billType/:billNumber/actions endpoint.
+    BillActions(bill::Actions),
+    /// The /bill/:congress/:billType/:billNumber/amendments endpoint.
+    BillAmendments(bill::Amendments),
+    /// The /bill/:congress/:billType/:billNumber/committees endpoint.
+    BillCommittees(bill::Committees),
+    /// The /bill/:congress/:billType/:billNumber/cosponsors endpoint.
+    BillCosponsors(bill::Cosponsors),
+    /// The /bill/:congress/:billType/:billNumber/relatedbills endpoint.
+    BillRelatedBills(bill::RelatedBills),
+    /// The /bill/:congress/:billType/:billNumber/subjects endpoint.
+    BillSubjects(bill::Subjects),
+    /// The /bill/:congress/:billType/:billNumber/summaries endpoint.
+    BillSummaries(bill::Summaries),
+    /// The /bill/:congress/:billType/:billNumber/text endpoint.
+    BillText(bill::Text),
+    /// The /bill/:congress/:billType/:billNumber/titles endpoint.
+    BillTitles(bill::Titles),
+    /// The /bound-congressional-record endpoint.
+    BoundCongressionalRecord(bound_congressional_record::BoundCongressionalRecord),
+    /// The /bound-congressional-record/:year endpoint.
+    BoundCongressionalRecordYear(bound_congressional_record::Year),
+    /// The /bound-congressional-record/:year/:month endpoint.
+    BoundCongressionalRecordMonth(bound_congressional_record::Month),
+    /// The /bound-congressional-record/:year/:month/:day endpoint.
+    BoundCongressionalRecordDay(bound_congressional_record::Day),
+    /// The /committee endpoint.
+    Committee(committee::Committee),
+    /// The /committee-meeting endpoint.
+    CommitteeMeeting(committee_meeting::CommitteeMeeting),
+    /// The /committee-meeting/:congress endpoint.
+    CommitteeMeetingCongress(committee_meeting::Congress),
+    /// The /committee-meeting/:congress/:chamber endpoint.
+    CommitteeMeetingChamber(committee_meeting::Chamber),
+    /// The /committee-meeting/:congress/:chamber/:eventId endpoint.
+    CommitteeMeetingEventId(committee_meeting::EventId),
+    /// The /committee-print endpoint.
+    CommitteePrint(committee_print::CommitteePrint),
+    /// The /committee-print/:congress endpoint.
+    CommitteePrintCongress(committee_print::Congress),
+    /// The /committee-print/:congress/:chamber endpoint.
+    CommitteePrintChamber(committee_print::Chamber),
+    /// The /committee-print/:congress/:chamber/:jacketNumber endpoint.
+    CommitteePrintJacketNumber(committee_print::JacketNumber),
+    /// The /committee-print/:congress/:chamber/:jacketNumber/text endpoint.
+    CommitteePrintText(committee_print::Text),
+    /// The /committee-report endpoint.
+    CommitteeReport(committee_report::CommitteeReport),
+    /// The /committee-report/:congress endpoint.
+    CommitteeReportCongress(committee_report::Congress),
+    /// The /committee-report/:congress/:reportType endpoint.
+    CommitteeReportReportType(committee_report::ReportType),
+    /// The /committee-report/:congress/:reportType/:reportNumber endpoint.
+    CommitteeReportReportNumber(committee_report::ReportNumber),
+    /// The /committee-report/:congress/:reportType/:reportNumber/text endpoint.
+    CommitteeReportText(committee_report::Text),
+    /// The /committee/:chamber endpoint.
+    CommitteeChamber(committee::Chamber),
+    /// The /committee/:chamber/:committeeCode endpoint.
+    CommitteeCode(committee::CommitteeCode<'static>),
+    /// The /committee/:chamber/:committeeCode/bills endpoint.
+    CommitteeBills(committee::Bills<'static>),
+    /// The /committee/:chamber/:committeeCode/house-communication endpoint.
+    CommitteeHouseCommunication(committee::HouseCommunication<'static>),
+    /// The /committee/:chamber/:committeeCode/nominations endpoint.
+    CommitteeNominations(committee::Nominations<'static>),
+    /// The /committee/:chamber/:committeeCode/reports endpoint.
+    CommitteeReports(committee::Reports<'static>),
+    /// The /committee/:chamber/:committeeCode/senate-communication endpoint.
+    CommitteeSenateCommunication(committee::SenateCommunication<'static>),
+    /// The /committee/:congress endpoint.
+    CommitteeCongress(committee::Congress),
+    /// The /committee/:congress/:chamber endpoint.
+    CommitteeChamberByCongress(committee::ChamberByCongress),
+    /// The /congress endpoint.
+    Congress(congress::Congress),
+    /// The /congress/current endpoint.
+    CurrentCongress(congress::CurrentCongress),
+    /// The /congress/:congress endpoint.
+    SpecificCongress(congress::SpecificCongress),
+    /// The /congressional-record endpoint.
+    CongressionalRecord(congressional_record::CongressionalRecord),
+    /// The /crsreport endpoint.
+    CrsReport(crsreport::CrsReport),
+    /// The /crsreport/:reportNumber endpoint.
+    CrsReportReportNumber(crsreport::ReportNumber<'static>),
+    /// The /daily-congressional-record endpoint.
+    DailyCongressionalRecord(daily_congressional_record::DailyCongressionalRecord),
+    /// The /daily-congressional-record/:volumeNumber endpoint.
+    DailyCongressionalRecordVolumeNumber(daily_congressional_record::VolumeNumber),
+    /// The /daily-congressional-record/:volumeNumber/:issueNumber endpoint.
+    DailyCongressionalRecordIssueNumber(daily_congressional_record::IssueNumber),
+    /// The /daily-congressional-record/:volumeNumber/:issueNumber/articles endpoint.
+    DailyCongressionalRecordArticles(daily_congressional_record::Articles),
+    /// The /hearing endpoint.
+    Hearing(hearing::Hearing),
+    /// The /hearing/:congress endpoint.
+    HearingCongress(hearing::Congress),
+    /// The /hearing/:congress/:chamber endpoint.
+    HearingChamber(hearing::Chamber),
+    /// The /hearing/:congress/:chamber/:jacketNumber endpoint.
+    HearingJacketNumber(hearing::JacketNumber),
+    /// The /house-communication endpoint.
+    HouseCommunication(house_communication::HouseCommunication),
+    /// The /house-communication/:congress endpoint.
+    HouseCommunicationCongress(house_communication::Congress),
+    /// The /house-communication/:congress/:communicationType endpoint.
+    HouseCommunicationCommunicationType(house_communication::CommunicationType),
+    /// The /house-communication/:congress/:communicationType/:communicationNumber endpoint.
+    HouseCommunicationCommunicationNumber(house_communication::CommunicationNumber),
+    /// The /house-requirement endpoint.
+    HouseRequirement(house_requirement::HouseRequirement),
+    /// The /house-requirement/:requirementNumber endpoint.
+    HouseRequirementRequirementNumber(house_requirement::RequirementNumber),
+    /// The /house-requirement/:requirementNumber/matching-communications endpoint.
+    HouseRequirementMatchingCommunications(house_requirement::MatchingCommunications),
+    /// The /law/:congress endpoint.
+    LawCongress(law::Congress),
+    /// The /law/:congress/:lawType endpoint.
+    LawType(law::LawType),
+    /// The /law/:congress/:lawType/:lawNumber endpoint.
+    LawNumber(law::LawNumber),
+    /// The /member endpoint.
+    Member(member::Member),
+    /// The /member/congress/:congress endpoint.
+    MemberCongress(member::Congress),
+    /// The /member/congress/:congress/:stateCode/:district endpoint.
+    MemberCongressStateCodeDistrict(member::CongressStateCodeDistrict),
+    /// The /member/:bioguideId endpoint.
+    MemberBioguideId(member::BioguideId<'static>),
+    /// The /member/:bioguideId/cosponsored-legislation endpoint.
+    MemberCosponsoredLegislation(member::CosponsoredLegislation<'static>),
+    /// The /member/:bioguideId/sponsored-legislation endpoint.
+    MemberSponsoredLegislation(member::SponsoredLegislation<'static>),
+    /// The /member/:stateCode endpoint.
+    MemberStateCode(member::StateCode),
+    /// The /member/:stateCode/:district endpoint.
+    MemberStateCodeDistrict(member::StateCodeDistrict),
+    /// The /nomination endpoint.
+    Nomination(nomination::Nomination),
+    /// The /nomination/:congress endpoint.
+    NominationCongress(nomination::Congress),
+    /// The /nomination/:congress/:nominationNumber endpoint.
+    NominationNumber(nomination::NominationNumber),
+    /// The /nomination/:congress/:nominationNumber/actions endpoint.
+    NominationActions(nomination::Actions),
+    /// The /nomination/:congress/:nominationNumber/committees endpoint.
+    NominationCommittees(nomination::Committees),
+    /// The /nomination/:congress/:nominationNumber/hearings endpoint.
+    NominationHearings(nomination::Hearings),
+    /// The /nomination/:congress/:nominationNumber/:ordinal endpoint.
+    NominationOrdinal(nomination::Ordinal),
+    /// The /senate-communication endpoint.
+    SenateCommunication(senate_communication::SenateCommunication),
+    /// The /senate-communication/:congress endpoint.
+    SenateCommunicationCongress(senate_communication::Congress),
+    /// The /senate-communication/:congress/:communicationType endpoint.
+    SenateCommunicationCommunicationType(senate_communication::CommunicationType),
+    /// The /senate-communication/:congress/:communicationType/:communicationNumber endpoint.
+    SenateCommunicationCommunicationNumber(senate_communication::CommunicationNumber),
+    /// The /summaries endpoint.
+    Summaries(summaries::Summaries),
+    /// The /summaries/:congress endpoint.
+    SummariesCongress(summaries::Congress),
+    /// The /summaries/:congress/:billType endpoint.
+    SummariesBillType(summaries::BillType),
+    /// The /treaty endpoint.
+    Treaty(treaty::Treaty),
+    /// The /treaty/:congress endpoint.
+    TreatyCongress(treaty::Congress),
+    /// The /treaty/:congress/:treatyNumber endpoint.
+    TreatyNumber(treaty::TreatyNumber),
+    /// The /treaty/:congress/:treatyNumber/actions endpoint.
+    TreatyNumberActions(treaty::TreatyNumberActions),
+    /// The /treaty/:congress/:treatyNumber/committees endpoint.
+    TreatyCommittees(treaty::Committees),
+    /// The /treaty/:congress/:treatyNumber/:treatySuffix endpoint.
+    TreatySuffix(treaty::TreatySuffix<'static>),
+    /// The /treaty/:congress/:treatyNumber/:treatySuffix/actions endpoint.
+    TreatySuffixActions(treaty::TreatySuffixActions<'static>),
+}
+
+impl Route {
+    /// Parse the endpoint for a path relative to the API root, taking its query parameters.
+    pub(crate) fn from_segments(
+        segments: &[&str],
+        params: &mut Params,
+    ) -> Result<Self, RouteError> {
+        if let ["amendment"] = segments {
+            let mut builder = amendments::Amendment::builder();
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::Amendment(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["bill"] = segments {
+            let mut builder = bill::Bill::builder();
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            if let Some(value) = params.take::<crate::api::common::Sort>("sort")? {
+                builder.sort(value);
+            }
+            return Ok(Route::Bill(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["bound-congressional-record"] = segments {
+            let mut builder = bound_congressional_record::BoundCongressionalRecord::builder();
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::BoundCongressionalRecord(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee"] = segments {
+            let mut builder = committee::Committee::builder();
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::Committee(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee-meeting"] = segments {
+            let mut builder = committee_meeting::CommitteeMeeting::builder();
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::CommitteeMeeting(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee-print"] = segments {
+            let mut builder = committee_print::CommitteePrint::builder();
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::CommitteePrint(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee-report"] = segments {
+            let mut builder = committee_report::CommitteeReport::builder();
+            if let Some(value) = params.take::<bool>("conference")? {
+                builder.conference(value);
+            }
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::CommitteeReport(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["congress"] = segments {
+            let mut builder = congress::Congress::builder();
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::Congress(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["congressional-record"] = segments {
+            let mut builder = congressional_record::CongressionalRecord::builder();
+            if let Some(value) = params.take::<u16>("y")? {
+                builder.year(value);
+            }
+            if let Some(value) = params.take::<u8>("m")? {
+                builder.month(value);
+            }
+            if let Some(value) = params.take::<u8>("d")? {
+                builder.day(value);
+            }
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::CongressionalRecord(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["crsreport"] = segments {
+            let mut builder = crsreport::CrsReport::builder();
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::CrsReport(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["daily-congressional-record"] = segments {
+            let mut builder = daily_congressional_record::DailyCongressionalRecord::builder();
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::DailyCongressionalRecord(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["hearing"] = segments {
+            let mut builder = hearing::Hearing::builder();
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::Hearing(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["house-communication"] = segments {
+            let mut builder = house_communication::HouseCommunication::builder();
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::HouseCommunication(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["house-requirement"] = segments {
+            let mut builder = house_requirement::HouseRequirement::builder();
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::HouseRequirement(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["member"] = segments {
+            let mut builder = member::Member::builder();
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            if let Some(value) = params.take::<bool>("currentMember")? {
+                builder.current_member(value);
+            }
+            return Ok(Route::Member(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["nomination"] = segments {
+            let mut builder = nomination::Nomination::builder();
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::Nomination(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["senate-communication"] = segments {
+            let mut builder = senate_communication::SenateCommunication::builder();
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::SenateCommunication(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["summaries"] = segments {
+            let mut builder = summaries::Summaries::builder();
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            if let Some(value) = params.take::<crate::api::common::Sort>("sort")? {
+                builder.sort(value);
+            }
+            return Ok(Route::Summaries(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["treaty"] = segments {
+            let mut builder = treaty::Treaty::builder();
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::Treaty(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["congress", "current"] = segments {
+            let mut builder = congress::CurrentCongress::builder();
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::CurrentCongress(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["member", "congress", congress] = segments
+            && let Some(congress) = segment::<u16>(congress)
+        {
+            let mut builder = member::Congress::builder();
+            builder.congress(congress);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<bool>("currentMember")? {
+                builder.current_member(value);
+            }
+            return Ok(Route::MemberCongress(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["member", "congress", congress, state_code, district] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(state_code) =
+                segment::<crate::api::member::CongressionalStateCode>(state_code)
+            && let Some(district) = segment::<u16>(district)
+        {
+            let mut builder = member::CongressStateCodeDistrict::builder();
+            builder
+                .congress(congress)
+                .state_code(state_code)
+                .district(district);
+            if let Some(value) = params.take::<bool>("currentMember")? {
+                builder.current_member(value);
+            }
+            return Ok(Route::MemberCongressStateCodeDistrict(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["amendment", congress] = segments
+            && let Some(congress) = segment::<u16>(congress)
+        {
+            let mut builder = amendments::Congress::builder();
+            builder.congress(congress);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::AmendmentCongress(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["bill", congress] = segments
+            && let Some(congress) = segment::<u16>(congress)
+        {
+            let mut builder = bill::Congress::builder();
+            builder.congress(congress);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            if let Some(value) = params.take::<crate::api::common::Sort>("sort")? {
+                builder.sort(value);
+            }
+            return Ok(Route::BillCongress(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["bound-congressional-record", year] = segments
+            && let Some(year) = segment::<u16>(year)
+        {
+            let mut builder = bound_congressional_record::Year::builder();
+            builder.year(year);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::BoundCongressionalRecordYear(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee-meeting", congress] = segments
+            && let Some(congress) = segment::<u16>(congress)
+        {
+            let mut builder = committee_meeting::Congress::builder();
+            builder.congress(congress);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::CommitteeMeetingCongress(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee-print", congress] = segments
+            && let Some(congress) = segment::<u16>(congress)
+        {
+            let mut builder = committee_print::Congress::builder();
+            builder.congress(congress);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::CommitteePrintCongress(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee-report", congress] = segments
+            && let Some(congress) = segment::<u16>(congress)
+        {
+            let mut builder = committee_report::Congress::builder();
+            builder.congress(congress);
+            if let Some(value) = params.take::<bool>("conference")? {
+                builder.conference(value);
+            }
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::CommitteeReportCongress(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee", chamber] = segments
+            && let Some(chamber) = segment::<crate::api::committee::CommitteeChamber>(chamber)
+        {
+            let mut builder = committee::Chamber::builder();
+            builder.chamber(chamber);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::CommitteeChamber(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee", congress] = segments
+            && let Some(congress) = segment::<u16>(congress)
+        {
+            let mut builder = committee::Congress::builder();
+            builder.congress(congress);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::CommitteeCongress(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["congress", congress] = segments
+            && let Some(congress) = segment::<u16>(congress)
+        {
+            let mut builder = congress::SpecificCongress::builder();
+            builder.congress(congress);
+            return Ok(Route::SpecificCongress(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["daily-congressional-record", volume_number] = segments
+            && let Some(volume_number) = segment::<u32>(volume_number)
+        {
+            let mut builder = daily_congressional_record::VolumeNumber::builder();
+            builder.volume_number(volume_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::DailyCongressionalRecordVolumeNumber(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["hearing", congress] = segments
+            && let Some(congress) = segment::<u16>(congress)
+        {
+            let mut builder = hearing::Congress::builder();
+            builder.congress(congress);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::HearingCongress(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["house-communication", congress] = segments
+            && let Some(congress) = segment::<u16>(congress)
+        {
+            let mut builder = house_communication::Congress::builder();
+            builder.congress(congress);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::HouseCommunicationCongress(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["house-requirement", requirement_number] = segments
+            && let Some(requirement_number) = segment::<u32>(requirement_number)
+        {
+            let mut builder = house_requirement::RequirementNumber::builder();
+            builder.requirement_number(requirement_number);
+            return Ok(Route::HouseRequirementRequirementNumber(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["law", congress] = segments
+            && let Some(congress) = segment::<u16>(congress)
+        {
+            let mut builder = law::Congress::builder();
+            builder.congress(congress);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::LawCongress(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["member", state_code] = segments
+            && let Some(state_code) =
+                segment::<crate::api::member::CongressionalStateCode>(state_code)
+        {
+            let mut builder = member::StateCode::builder();
+            builder.state_code(state_code);
+            if let Some(value) = params.take::<bool>("currentMember")? {
+                builder.current_member(value);
+            }
+            return Ok(Route::MemberStateCode(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["nomination", congress] = segments
+            && let Some(congress) = segment::<u16>(congress)
+        {
+            let mut builder = nomination::Congress::builder();
+            builder.congress(congress);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::NominationCongress(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["senate-communication", congress] = segments
+            && let Some(congress) = segment::<u16>(congress)
+        {
+            let mut builder = senate_communication::Congress::builder();
+            builder.congress(congress);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::SenateCommunicationCongress(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["summaries", congress] = segments
+            && let Some(congress) = segment::<u16>(congress)
+        {
+            let mut builder = summaries::Congress::builder();
+            builder.congress(congress);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            if let Some(value) = params.take::<crate::api::common::Sort>("sort")? {
+                builder.sort(value);
+            }
+            return Ok(Route::SummariesCongress(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["treaty", congress] = segments
+            && let Some(congress) = segment::<u16>(congress)
+        {
+            let mut builder = treaty::Congress::builder();
+            builder.congress(congress);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::TreatyCongress(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let [
+            "house-requirement",
+            requirement_number,
+            "matching-communications",
+        ] = segments
+            && let Some(requirement_number) = segment::<u32>(requirement_number)
+        {
+            let mut builder = house_requirement::MatchingCommunications::builder();
+            builder.requirement_number(requirement_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::HouseRequirementMatchingCommunications(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["amendment", congress, amendment_type] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(amendment_type) =
+                segment::<crate::api::amendments::CongressionalAmendmentType>(amendment_type)
+        {
+            let mut builder = amendments::AmendmentType::builder();
+            builder.congress(congress).amendment_type(amendment_type);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::AmendmentType(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["bill", congress, bill_type] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(bill_type) = segment::<crate::api::common::BillType>(bill_type)
+        {
+            let mut builder = bill::BillType::builder();
+            builder.congress(congress).bill_type(bill_type);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            if let Some(value) = params.take::<crate::api::common::Sort>("sort")? {
+                builder.sort(value);
+            }
+            return Ok(Route::BillType(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["bound-congressional-record", year, month] = segments
+            && let Some(year) = segment::<u16>(year)
+            && let Some(month) = segment::<u8>(month)
+        {
+            let mut builder = bound_congressional_record::Month::builder();
+            builder.year(year).month(month);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::BoundCongressionalRecordMonth(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee-meeting", congress, chamber] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(chamber) = segment::<crate::api::common::CommitteeChamber>(chamber)
+        {
+            let mut builder = committee_meeting::Chamber::builder();
+            builder.congress(congress).chamber(chamber);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::CommitteeMeetingChamber(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee-print", congress, chamber] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(chamber) = segment::<crate::api::common::CommitteeChamber>(chamber)
+        {
+            let mut builder = committee_print::Chamber::builder();
+            builder.congress(congress).chamber(chamber);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::CommitteePrintChamber(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee-report", congress, report_type] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(report_type) =
+                segment::<crate::api::committee_report::CommitteeReportType>(report_type)
+        {
+            let mut builder = committee_report::ReportType::builder();
+            builder.congress(congress).report_type(report_type);
+            if let Some(value) = params.take::<bool>("conference")? {
+                builder.conference(value);
+            }
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::CommitteeReportReportType(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee", congress, chamber] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(chamber) = segment::<crate::api::committee::CommitteeChamber>(chamber)
+        {
+            let mut builder = committee::ChamberByCongress::builder();
+            builder.congress(congress).chamber(chamber);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::CommitteeChamberByCongress(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["daily-congressional-record", volume_number, issue_number] = segments
+            && let Some(volume_number) = segment::<u32>(volume_number)
+            && let Some(issue_number) = segment::<u32>(issue_number)
+        {
+            let mut builder = daily_congressional_record::IssueNumber::builder();
+            builder
+                .volume_number(volume_number)
+                .issue_number(issue_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::DailyCongressionalRecordIssueNumber(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["hearing", congress, chamber] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(chamber) = segment::<crate::api::common::CommitteeChamber>(chamber)
+        {
+            let mut builder = hearing::Chamber::builder();
+            builder.congress(congress).chamber(chamber);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::HearingChamber(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["house-communication", congress, communication_type] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(communication_type) = segment::<
+                crate::api::house_communication::HouseCommunicationType,
+            >(communication_type)
+        {
+            let mut builder = house_communication::CommunicationType::builder();
+            builder
+                .congress(congress)
+                .communication_type(communication_type);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::HouseCommunicationCommunicationType(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["law", congress, law_type] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(law_type) = segment::<crate::api::law::CongressionalLawType>(law_type)
+        {
+            let mut builder = law::LawType::builder();
+            builder.congress(congress).law_type(law_type);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::LawType(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["member", state_code, district] = segments
+            && let Some(state_code) =
+                segment::<crate::api::member::CongressionalStateCode>(state_code)
+            && let Some(district) = segment::<u16>(district)
+        {
+            let mut builder = member::StateCodeDistrict::builder();
+            builder.state_code(state_code).district(district);
+            if let Some(value) = params.take::<bool>("currentMember")? {
+                builder.current_member(value);
+            }
+            return Ok(Route::MemberStateCodeDistrict(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["nomination", congress, nomination_number] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(nomination_number) = segment::<u32>(nomination_number)
+        {
+            let mut builder = nomination::NominationNumber::builder();
+            builder
+                .congress(congress)
+                .nomination_number(nomination_number);
+            return Ok(Route::NominationNumber(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["senate-communication", congress, communication_type] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(communication_type) = segment::<
+                crate::api::senate_communication::SenateCommunicationType,
+            >(communication_type)
+        {
+            let mut builder = senate_communication::CommunicationType::builder();
+            builder
+                .congress(congress)
+                .communication_type(communication_type);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::SenateCommunicationCommunicationType(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["summaries", congress, bill_type] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(bill_type) = segment::<crate::api::common::BillType>(bill_type)
+        {
+            let mut builder = summaries::BillType::builder();
+            builder.congress(congress).bill_type(bill_type);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            if let Some(value) = params.take::<crate::api::common::Sort>("sort")? {
+                builder.sort(value);
+            }
+            return Ok(Route::SummariesBillType(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["treaty", congress, treaty_number] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(treaty_number) = segment::<u32>(treaty_number)
+        {
+            let mut builder = treaty::TreatyNumber::builder();
+            builder.congress(congress).treaty_number(treaty_number);
+            return Ok(Route::TreatyNumber(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let [
+            "daily-congressional-record",
+            volume_number,
+            issue_number,
+            "articles",
+        ] = segments
+            && let Some(volume_number) = segment::<u32>(volume_number)
+            && let Some(issue_number) = segment::<u32>(issue_number)
+        {
+            let mut builder = daily_congressional_record::Articles::builder();
+            builder
+                .volume_number(volume_number)
+                .issue_number(issue_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::DailyCongressionalRecordArticles(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["nomination", congress, nomination_number, "actions"] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(nomination_number) = segment::<u32>(nomination_number)
+        {
+            let mut builder = nomination::Actions::builder();
+            builder
+                .congress(congress)
+                .nomination_number(nomination_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::NominationActions(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["nomination", congress, nomination_number, "committees"] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(nomination_number) = segment::<u32>(nomination_number)
+        {
+            let mut builder = nomination::Committees::builder();
+            builder
+                .congress(congress)
+                .nomination_number(nomination_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::NominationCommittees(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["nomination", congress, nomination_number, "hearings"] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(nomination_number) = segment::<u32>(nomination_number)
+        {
+            let mut builder = nomination::Hearings::builder();
+            builder
+                .congress(congress)
+                .nomination_number(nomination_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::NominationHearings(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["treaty", congress, treaty_number, "actions"] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(treaty_number) = segment::<u32>(treaty_number)
+        {
+            let mut builder = treaty::TreatyNumberActions::builder();
+            builder.congress(congress).treaty_number(treaty_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::TreatyNumberActions(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["treaty", congress, treaty_number, "committees"] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(treaty_number) = segment::<u32>(treaty_number)
+        {
+            let mut builder = treaty::Committees::builder();
+            builder.congress(congress).treaty_number(treaty_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::TreatyCommittees(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["amendment", congress, amendment_type, amendment_number] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(amendment_type) =
+                segment::<crate::api::amendments::CongressionalAmendmentType>(amendment_type)
+            && let Some(amendment_number) = segment::<u32>(amendment_number)
+        {
+            let mut builder = amendments::AmendmentNumber::builder();
+            builder
+                .congress(congress)
+                .amendment_type(amendment_type)
+                .amendment_number(amendment_number);
+            return Ok(Route::AmendmentNumber(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["bill", congress, bill_type, bill_number] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(bill_type) = segment::<crate::api::common::BillType>(bill_type)
+            && let Some(bill_number) = segment::<u32>(bill_number)
+        {
+            let mut builder = bill::BillNumber::builder();
+            builder
+                .congress(congress)
+                .bill_type(bill_type)
+                .bill_number(bill_number);
+            return Ok(Route::BillNumber(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["bound-congressional-record", year, month, day] = segments
+            && let Some(year) = segment::<u16>(year)
+            && let Some(month) = segment::<u8>(month)
+            && let Some(day) = segment::<u8>(day)
+        {
+            let mut builder = bound_congressional_record::Day::builder();
+            builder.year(year).month(month).day(day);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::BoundCongressionalRecordDay(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee-meeting", congress, chamber, event_id] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(chamber) = segment::<crate::api::common::CommitteeChamber>(chamber)
+            && let Some(event_id) = segment::<u32>(event_id)
+        {
+            let mut builder = committee_meeting::EventId::builder();
+            builder
+                .congress(congress)
+                .chamber(chamber)
+                .event_id(event_id);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::CommitteeMeetingEventId(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee-print", congress, chamber, jacket_number] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(chamber) = segment::<crate::api::common::CommitteeChamber>(chamber)
+            && let Some(jacket_number) = segment::<u32>(jacket_number)
+        {
+            let mut builder = committee_print::JacketNumber::builder();
+            builder
+                .congress(congress)
+                .chamber(chamber)
+                .jacket_number(jacket_number);
+            return Ok(Route::CommitteePrintJacketNumber(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee-report", congress, report_type, report_number] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(report_type) =
+                segment::<crate::api::committee_report::CommitteeReportType>(report_type)
+            && let Some(report_number) = segment::<u32>(report_number)
+        {
+            let mut builder = committee_report::ReportNumber::builder();
+            builder
+                .congress(congress)
+                .report_type(report_type)
+                .report_number(report_number);
+            return Ok(Route::CommitteeReportReportNumber(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["hearing", congress, chamber, jacket_number] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(chamber) = segment::<crate::api::common::CommitteeChamber>(chamber)
+            && let Some(jacket_number) = segment::<u32>(jacket_number)
+        {
+            let mut builder = hearing::JacketNumber::builder();
+            builder
+                .congress(congress)
+                .chamber(chamber)
+                .jacket_number(jacket_number);
+            return Ok(Route::HearingJacketNumber(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let [
+            "house-communication",
+            congress,
+            communication_type,
+            communication_number,
+        ] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(communication_type) = segment::<
+                crate::api::house_communication::HouseCommunicationType,
+            >(communication_type)
+            && let Some(communication_number) = segment::<u32>(communication_number)
+        {
+            let mut builder = house_communication::CommunicationNumber::builder();
+            builder
+                .congress(congress)
+                .communication_type(communication_type)
+                .communication_number(communication_number);
+            return Ok(Route::HouseCommunicationCommunicationNumber(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["law", congress, law_type, law_number] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(law_type) = segment::<crate::api::law::CongressionalLawType>(law_type)
+            && let Some(law_number) = segment::<u32>(law_number)
+        {
+            let mut builder = law::LawNumber::builder();
+            builder
+                .congress(congress)
+                .law_type(law_type)
+                .law_number(law_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::LawNumber(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["nomination", congress, nomination_number, ordinal] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(nomination_number) = segment::<u32>(nomination_number)
+            && let Some(ordinal) = segment::<u32>(ordinal)
+        {
+            let mut builder = nomination::Ordinal::builder();
+            builder
+                .congress(congress)
+                .nomination_number(nomination_number)
+                .ordinal(ordinal);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::NominationOrdinal(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let [
+            "senate-communication",
+            congress,
+            communication_type,
+            communication_number,
+        ] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(communication_type) = segment::<
+                crate::api::senate_communication::SenateCommunicationType,
+            >(communication_type)
+            && let Some(communication_number) = segment::<u32>(communication_number)
+        {
+            let mut builder = senate_communication::CommunicationNumber::builder();
+            builder
+                .congress(congress)
+                .communication_type(communication_type)
+                .communication_number(communication_number);
+            return Ok(Route::SenateCommunicationCommunicationNumber(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let [
+            "amendment",
+            congress,
+            amendment_type,
+            amendment_number,
+            "actions",
+        ] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(amendment_type) =
+                segment::<crate::api::amendments::CongressionalAmendmentType>(amendment_type)
+            && let Some(amendment_number) = segment::<u32>(amendment_number)
+        {
+            let mut builder = amendments::Actions::builder();
+            builder
+                .congress(congress)
+                .amendment_type(amendment_type)
+                .amendment_number(amendment_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::AmendmentActions(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let [
+            "amendment",
+            congress,
+            amendment_type,
+            amendment_number,
+            "amendments",
+        ] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(amendment_type) =
+                segment::<crate::api::amendments::CongressionalAmendmentType>(amendment_type)
+            && let Some(amendment_number) = segment::<u32>(amendment_number)
+        {
+            let mut builder = amendments::Amendments::builder();
+            builder
+                .congress(congress)
+                .amendment_type(amendment_type)
+                .amendment_number(amendment_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::AmendmentAmendments(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let [
+            "amendment",
+            congress,
+            amendment_type,
+            amendment_number,
+            "cosponsors",
+        ] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(amendment_type) =
+                segment::<crate::api::amendments::CongressionalAmendmentType>(amendment_type)
+            && let Some(amendment_number) = segment::<u32>(amendment_number)
+        {
+            let mut builder = amendments::Cosponsors::builder();
+            builder
+                .congress(congress)
+                .amendment_type(amendment_type)
+                .amendment_number(amendment_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::AmendmentCosponsors(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let [
+            "amendment",
+            congress,
+            amendment_type,
+            amendment_number,
+            "text",
+        ] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(amendment_type) =
+                segment::<crate::api::amendments::CongressionalAmendmentType>(amendment_type)
+            && let Some(amendment_number) = segment::<u32>(amendment_number)
+        {
+            let mut builder = amendments::Text::builder();
+            builder
+                .congress(congress)
+                .amendment_type(amendment_type)
+                .amendment_number(amendment_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::AmendmentText(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["bill", congress, bill_type, bill_number, "actions"] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(bill_type) = segment::<crate::api::common::BillType>(bill_type)
+            && let Some(bill_number) = segment::<u32>(bill_number)
+        {
+            let mut builder = bill::Actions::builder();
+            builder
+                .congress(congress)
+                .bill_type(bill_type)
+                .bill_number(bill_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::BillActions(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["bill", congress, bill_type, bill_number, "amendments"] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(bill_type) = segment::<crate::api::common::BillType>(bill_type)
+            && let Some(bill_number) = segment::<u32>(bill_number)
+        {
+            let mut builder = bill::Amendments::builder();
+            builder
+                .congress(congress)
+                .bill_type(bill_type)
+                .bill_number(bill_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::BillAmendments(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["bill", congress, bill_type, bill_number, "committees"] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(bill_type) = segment::<crate::api::common::BillType>(bill_type)
+            && let Some(bill_number) = segment::<u32>(bill_number)
+        {
+            let mut builder = bill::Committees::builder();
+            builder
+                .congress(congress)
+                .bill_type(bill_type)
+                .bill_number(bill_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::BillCommittees(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["bill", congress, bill_type, bill_number, "cosponsors"] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(bill_type) = segment::<crate::api::common::BillType>(bill_type)
+            && let Some(bill_number) = segment::<u32>(bill_number)
+        {
+            let mut builder = bill::Cosponsors::builder();
+            builder
+                .congress(congress)
+                .bill_type(bill_type)
+                .bill_number(bill_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::BillCosponsors(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["bill", congress, bill_type, bill_number, "relatedbills"] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(bill_type) = segment::<crate::api::common::BillType>(bill_type)
+            && let Some(bill_number) = segment::<u32>(bill_number)
+        {
+            let mut builder = bill::RelatedBills::builder();
+            builder
+                .congress(congress)
+                .bill_type(bill_type)
+                .bill_number(bill_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::BillRelatedBills(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["bill", congress, bill_type, bill_number, "subjects"] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(bill_type) = segment::<crate::api::common::BillType>(bill_type)
+            && let Some(bill_number) = segment::<u32>(bill_number)
+        {
+            let mut builder = bill::Subjects::builder();
+            builder
+                .congress(congress)
+                .bill_type(bill_type)
+                .bill_number(bill_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::BillSubjects(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["bill", congress, bill_type, bill_number, "summaries"] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(bill_type) = segment::<crate::api::common::BillType>(bill_type)
+            && let Some(bill_number) = segment::<u32>(bill_number)
+        {
+            let mut builder = bill::Summaries::builder();
+            builder
+                .congress(congress)
+                .bill_type(bill_type)
+                .bill_number(bill_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::BillSummaries(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["bill", congress, bill_type, bill_number, "text"] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(bill_type) = segment::<crate::api::common::BillType>(bill_type)
+            && let Some(bill_number) = segment::<u32>(bill_number)
+        {
+            let mut builder = bill::Text::builder();
+            builder
+                .congress(congress)
+                .bill_type(bill_type)
+                .bill_number(bill_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::BillText(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["bill", congress, bill_type, bill_number, "titles"] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(bill_type) = segment::<crate::api::common::BillType>(bill_type)
+            && let Some(bill_number) = segment::<u32>(bill_number)
+        {
+            let mut builder = bill::Titles::builder();
+            builder
+                .congress(congress)
+                .bill_type(bill_type)
+                .bill_number(bill_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::BillTitles(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee-print", congress, chamber, jacket_number, "text"] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(chamber) = segment::<crate::api::common::CommitteeChamber>(chamber)
+            && let Some(jacket_number) = segment::<u32>(jacket_number)
+        {
+            let mut builder = committee_print::Text::builder();
+            builder
+                .congress(congress)
+                .chamber(chamber)
+                .jacket_number(jacket_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::CommitteePrintText(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let [
+            "committee-report",
+            congress,
+            report_type,
+            report_number,
+            "text",
+        ] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(report_type) =
+                segment::<crate::api::committee_report::CommitteeReportType>(report_type)
+            && let Some(report_number) = segment::<u32>(report_number)
+        {
+            let mut builder = committee_report::Text::builder();
+            builder
+                .congress(congress)
+                .report_type(report_type)
+                .report_number(report_number);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::CommitteeReportText(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["treaty", congress, treaty_number, treaty_suffix] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(treaty_number) = segment::<u32>(treaty_number)
+            && let Some(treaty_suffix) = segment::<Cow<'static, str>>(treaty_suffix)
+        {
+            let mut builder = treaty::TreatySuffix::builder();
+            builder
+                .congress(congress)
+                .treaty_number(treaty_number)
+                .treaty_suffix(treaty_suffix);
+            return Ok(Route::TreatySuffix(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["treaty", congress, treaty_number, treaty_suffix, "actions"] = segments
+            && let Some(congress) = segment::<u16>(congress)
+            && let Some(treaty_number) = segment::<u32>(treaty_number)
+            && let Some(treaty_suffix) = segment::<Cow<'static, str>>(treaty_suffix)
+        {
+            let mut builder = treaty::TreatySuffixActions::builder();
+            builder
+                .congress(congress)
+                .treaty_number(treaty_number)
+                .treaty_suffix(treaty_suffix);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::TreatySuffixActions(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee", chamber, committee_code] = segments
+            && let Some(chamber) = segment::<crate::api::committee::CommitteeChamber>(chamber)
+            && let Some(committee_code) = segment::<Cow<'static, str>>(committee_code)
+        {
+            let mut builder = committee::CommitteeCode::builder();
+            builder.chamber(chamber).committee_code(committee_code);
+            return Ok(Route::CommitteeCode(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee", chamber, committee_code, "bills"] = segments
+            && let Some(chamber) = segment::<crate::api::committee::CommitteeChamber>(chamber)
+            && let Some(committee_code) = segment::<Cow<'static, str>>(committee_code)
+        {
+            let mut builder = committee::Bills::builder();
+            builder.chamber(chamber).committee_code(committee_code);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::CommitteeBills(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee", chamber, committee_code, "house-communication"] = segments
+            && let Some(chamber) = segment::<crate::api::committee::CommitteeChamber>(chamber)
+            && let Some(committee_code) = segment::<Cow<'static, str>>(committee_code)
+        {
+            let mut builder = committee::HouseCommunication::builder();
+            builder.chamber(chamber).committee_code(committee_code);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::CommitteeHouseCommunication(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee", chamber, committee_code, "nominations"] = segments
+            && let Some(chamber) = segment::<crate::api::committee::CommitteeChamber>(chamber)
+            && let Some(committee_code) = segment::<Cow<'static, str>>(committee_code)
+        {
+            let mut builder = committee::Nominations::builder();
+            builder.chamber(chamber).committee_code(committee_code);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::CommitteeNominations(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee", chamber, committee_code, "reports"] = segments
+            && let Some(chamber) = segment::<crate::api::committee::CommitteeChamber>(chamber)
+            && let Some(committee_code) = segment::<Cow<'static, str>>(committee_code)
+        {
+            let mut builder = committee::Reports::builder();
+            builder.chamber(chamber).committee_code(committee_code);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("fromDateTime")? {
+                builder.from_date_time(value);
+            }
+            if let Some(value) = params.take::<DateTime<Utc>>("toDateTime")? {
+                builder.to_date_time(value);
+            }
+            return Ok(Route::CommitteeReports(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["committee", chamber, committee_code, "senate-communication"] = segments
+            && let Some(chamber) = segment::<crate::api::committee::CommitteeChamber>(chamber)
+            && let Some(committee_code) = segment::<Cow<'static, str>>(committee_code)
+        {
+            let mut builder = committee::SenateCommunication::builder();
+            builder.chamber(chamber).committee_code(committee_code);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::CommitteeSenateCommunication(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["crsreport", report_number] = segments
+            && let Some(report_number) = segment::<Cow<'static, str>>(report_number)
+        {
+            let mut builder = crsreport::ReportNumber::builder();
+            builder.report_number(report_number);
+            return Ok(Route::CrsReportReportNumber(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["member", bioguide_id] = segments
+            && let Some(bioguide_id) = segment::<Cow<'static, str>>(bioguide_id)
+        {
+            let mut builder = member::BioguideId::builder();
+            builder.bioguide_id(bioguide_id);
+            return Ok(Route::MemberBioguideId(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["member", bioguide_id, "cosponsored-legislation"] = segments
+            && let Some(bioguide_id) = segment::<Cow<'static, str>>(bioguide_id)
+        {
+            let mut builder = member::CosponsoredLegislation::builder();
+            builder.bioguide_id(bioguide_id);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::MemberCosponsoredLegislation(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        if let ["member", bioguide_id, "sponsored-legislation"] = segments
+            && let Some(bioguide_id) = segment::<Cow<'static, str>>(bioguide_id)
+        {
+            let mut builder = member::SponsoredLegislation::builder();
+            builder.bioguide_id(bioguide_id);
+            if let Some(value) = params.take::<u32>("offset")? {
+                builder.offset(value);
+            }
+            if let Some(value) = params.take::<u8>("limit")? {
+                builder.limit(value);
+            }
+            return Ok(Route::MemberSponsoredLegislation(
+                builder.build().expect("the path parameters are set"),
+            ));
+        }
+
+        Err(RouteError::UnknownPath {
+            path: segments.join("/"),
+        })
+    }
+
+    /// Whether the endpoint is [`Pageable`](crate::api::Pageable), i.e. responds with a list of items.
+    pub fn is_pageable(&self) -> bool {
+        matches!(
+            self,
+            Route::Amendment(_)
+                | Route::AmendmentCongress(_)
+                | Route::AmendmentType(_)
+                | Route::AmendmentActions(_)
+                | Route::AmendmentAmendments(_)
+                | Route::AmendmentCosponsors(_)
+                | Route::AmendmentText(_)
+                | Route::Bill(_)
+                | Route::BillCongress(_)
+                | Route::BillType(_)
+                | Route::BillActions(_)
+                | Route::BillAmendments(_)
+                | Route::BillCommittees(_)
+                | Route::BillCosponsors(_)
+                | Route::BillRelatedBills(_)
+                | Route::BillSummaries(_)
+                | Route::BillText(_)
+                | Route::BillTitles(_)
+                | Route::BoundCongressionalRecord(_)
+                | Route::BoundCongressionalRecordYear(_)
+                | Route::BoundCongressionalRecordMonth(_)
+                | Route::BoundCongressionalRecordDay(_)
+                | Route::Committee(_)
+                | Route::CommitteeMeeting(_)
+                | Route::CommitteeMeetingCongress(_)
+                | Route::CommitteeMeetingChamber(_)
+                | Route::CommitteePrint(_)
+                | Route::CommitteePrintCongress(_)
+                | Route::CommitteePrintChamber(_)
+                | Route::CommitteePrintText(_)
+                | Route::CommitteeReport(_)
+                | Route::CommitteeReportCongress(_)
+                | Route::CommitteeReportReportType(_)
+                | Route::CommitteeReportText(_)
+                | Route::CommitteeChamber(_)
+                | Route::CommitteeHouseCommunication(_)
+                | Route::CommitteeNominations(_)
+                | Route::CommitteeReports(_)
+                | Route::CommitteeSenateCommunication(_)
+                | Route::CommitteeCongress(_)
+                | Route::CommitteeChamberByCongress(_)
+                | Route::Congress(_)
+                | Route::CrsReport(_)
+                | Route::DailyCongressionalRecord(_)
+                | Route::DailyCongressionalRecordVolumeNumber(_)
+                | Route::DailyCongressionalRecordArticles(_)
+                | Route::Hearing(_)
+                | Route::HearingCongress(_)
+                | Route::HearingChamber(_)
+                | Route::HouseCommunication(_)
+                | Route::HouseCommunicationCongress(_)
+                | Route::HouseCommunicationCommunicationType(_)
+                | Route::HouseRequirement(_)
+                | Route::HouseRequirementMatchingCommunications(_)
+                | Route::LawCongress(_)
+                | Route::LawType(_)
+                | Route::Member(_)
+                | Route::MemberCongress(_)
+                | Route::MemberCosponsoredLegislation(_)
+                | Route::MemberSponsoredLegislation(_)
+                | Route::Nomination(_)
+                | Route::NominationCongress(_)
+                | Route::NominationActions(_)
+                | Route::NominationCommittees(_)
+                | Route::NominationHearings(_)
+                | Route::NominationOrdinal(_)
+                | Route::SenateCommunication(_)
+                | Route::SenateCommunicationCongress(_)
+                | Route::SenateCommunicationCommunicationType(_)
+                | Route::Summaries(_)
+                | Route::SummariesCongress(_)
+                | Route::SummariesBillType(_)
+                | Route::Treaty(_)
+                | Route::TreatyCongress(_)
+                | Route::TreatyNumberActions(_)
+                | Route::TreatyCommittees(_)
+                | Route::TreatySuffixActions(_)
+        )
+    }
+
+    pub(crate) fn as_endpoint(&self) -> &dyn Endpoint {
+        match self {
+            Route::Amendment(endpoint) => endpoint,
+            Route::AmendmentCongress(endpoint) => endpoint,
+            Route::AmendmentType(endpoint) => endpoint,
+            Route::AmendmentNumber(endpoint) => endpoint,
+            Route::AmendmentActions(endpoint) => endpoint,
+            Route::AmendmentAmendments(endpoint) => endpoint,
+            Route::AmendmentCosponsors(endpoint) => endpoint,
+            Route::AmendmentText(endpoint) => endpoint,
+            Route::Bill(endpoint) => endpoint,
+            Route::BillCongress(endpoint) => endpoint,
+            Route::BillType(endpoint) => endpoint,
+            Route::BillNumber(endpoint) => endpoint,
+            Route::BillActions(endpoint) => endpoint,
+            Route::BillAmendments(endpoint) => endpoint,
+            Route::BillCommittees(endpoint) => endpoint,
+            Route::BillCosponsors(endpoint) => endpoint,
+            Route::BillRelatedBills(endpoint) => endpoint,
+            Route::BillSubjects(endpoint) => endpoint,
+            Route::BillSummaries(endpoint) => endpoint,
+            Route::BillText(endpoint) => endpoint,
+            Route::BillTitles(endpoint) => endpoint,
+            Route::BoundCongressionalRecord(endpoint) => endpoint,
+            Route::BoundCongressionalRecordYear(endpoint) => endpoint,
+            Route::BoundCongressionalRecordMonth(endpoint) => endpoint,
+            Route::BoundCongressionalRecordDay(endpoint) => endpoint,
+            Route::Committee(endpoint) => endpoint,
+            Route::CommitteeMeeting(endpoint) => endpoint,
+            Route::CommitteeMeetingCongress(endpoint) => endpoint,
+            Route::CommitteeMeetingChamber(endpoint) => endpoint,
+            Route::CommitteeMeetingEventId(endpoint) => endpoint,
+            Route::CommitteePrint(endpoint) => endpoint,
+            Route::CommitteePrintCongress(endpoint) => endpoint,
+            Route::CommitteePrintChamber(endpoint) => endpoint,
+            Route::CommitteePrintJacketNumber(endpoint) => endpoint,
+            Route::CommitteePrintText(endpoint) => endpoint,
+            Route::CommitteeReport(endpoint) => endpoint,
+            Route::CommitteeReportCongress(endpoint) => endpoint,
+            Route::CommitteeReportReportType(endpoint) => endpoint,
+            Route::CommitteeReportReportNumber(endpoint) => endpoint,
+            Route::CommitteeReportText(endpoint) => endpoint,
+            Route::CommitteeChamber(endpoint) => endpoint,
+            Route::CommitteeCode(endpoint) => endpoint,
+            Route::CommitteeBills(endpoint) => endpoint,
+            Route::CommitteeHouseCommunication(endpoint) => endpoint,
+            Route::CommitteeNominations(endpoint) => endpoint,
+            Route::CommitteeReports(endpoint) => endpoint,
+            Route::CommitteeSenateCommunication(endpoint) => endpoint,
+            Route::CommitteeCongress(endpoint) => endpoint,
+            Route::CommitteeChamberByCongress(endpoint) => endpoint,
+            Route::Congress(endpoint) => endpoint,
+            Route::CurrentCongress(endpoint) => endpoint,
+            Route::SpecificCongress(endpoint) => endpoint,
+            Route::CongressionalRecord(endpoint) => endpoint,
+            Route::CrsReport(endpoint) => endpoint,
+            Route::CrsReportReportNumber(endpoint) => endpoint,
+            Route::DailyCongressionalRecord(endpoint) => endpoint,
+            Route::DailyCongressionalRecordVolumeNumber(endpoint) => endpoint,
+            Route::DailyCongressionalRecordIssueNumber(endpoint) => endpoint,
+            Route::DailyCongressionalRecordArticles(endpoint) => endpoint,
+            Route::Hearing(endpoint) => endpoint,
+            Route::HearingCongress(endpoint) => endpoint,
+            Route::HearingChamber(endpoint) => endpoint,
+            Route::HearingJacketNumber(endpoint) => endpoint,
+            Route::HouseCommunication(endpoint) => endpoint,
+            Route::HouseCommunicationCongress(endpoint) => endpoint,
+            Route::HouseCommunicationCommunicationType(endpoint) => endpoint,
+            Route::HouseCommunicationCommunicationNumber(endpoint) => endpoint,
+            Route::HouseRequirement(endpoint) => endpoint,
+            Route::HouseRequirementRequirementNumber(endpoint) => endpoint,
+            Route::HouseRequirementMatchingCommunications(endpoint) => endpoint,
+            Route::LawCongress(endpoint) => endpoint,
+            Route::LawType(endpoint) => endpoint,
+            Route::LawNumber(endpoint) => endpoint,
+            Route::Member(endpoint) => endpoint,
+            Route::MemberCongress(endpoint) => endpoint,
+            Route::MemberCongressStateCodeDistrict(endpoint) => endpoint,
+            Route::MemberBioguideId(endpoint) => endpoint,
+            Route::MemberCosponsoredLegislation(endpoint) => endpoint,
+            Route::MemberSponsoredLegislation(endpoint) => endpoint,
+            Route::MemberStateCode(endpoint) => endpoint,
+            Route::MemberStateCodeDistrict(endpoint) => endpoint,
+            Route::Nomination(endpoint) => endpoint,
+            Route::NominationCongress(endpoint) => endpoint,
+            Route::NominationNumber(endpoint) => endpoint,
+            Route::NominationActions(endpoint) => endpoint,
+            Route::NominationCommittees(endpoint) => endpoint,
+            Route::NominationHearings(endpoint) => endpoint,
+            Route::NominationOrdinal(endpoint) => endpoint,
+            Route::SenateCommunication(endpoint) => endpoint,
+            Route::SenateCommunicationCongress(endpoint) => endpoint,
+            Route::SenateCommunicationCommunicationType(endpoint) => endpoint,
+            Route::SenateCommunicationCommunicationNumber(endpoint) => endpoint,
+            Route::Summaries(endpoint) => endpoint,
+            Route::SummariesCongress(endpoint) => endpoint,
+            Route::SummariesBillType(endpoint) => endpoint,
+            Route::Treaty(endpoint) => endpoint,
+            Route::TreatyCongress(endpoint) => endpoint,
+            Route::TreatyNumber(endpoint) => endpoint,
+            Route::TreatyNumberActions(endpoint) => endpoint,
+            Route::TreatyCommittees(endpoint) => endpoint,
+            Route::TreatySuffix(endpoint) => endpoint,
+            Route::TreatySuffixActions(endpoint) => endpoint,
+        }
+    }
+}
@@ -32,7 +32,7 @@ pub enum SenateCommunicationType {
 }
 
 impl SenateCommunicationType {
-    fn as_str(self) -> &'static str {
+    pub(crate) fn as_str(self) -> &'static str {
         match self {
             SenateCommunicationType::Ec => "ec",
             SenateCommunicationType::Pm => "pm",
@@ -1,17 +1,21 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::params::QueryParams};
-
-use super::SenateCommunicationType;
+use crate::{
+    api::endpoint::Endpoint, api::paged::Pageable, api::params::QueryParams,
+    api::senate_communication::SenateCommunicationType,
+};
 
 /// Represents the /senate-communication/:congress/:communicationType endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct CommunicationType {
-    #[builder(default)]
-    congress: u8,
+    #[builder(setter(into))]
+    congress: u16,
     #[builder(setter(into))]
     communication_type: SenateCommunicationType,
     #[builder(default)]
@@ -24,6 +28,16 @@ impl CommunicationType {
     pub fn builder() -> CommunicationTypeBuilder {
         CommunicationTypeBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> CommunicationTypeBuilder {
+        CommunicationTypeBuilder {
+            congress: Some(self.congress),
+            communication_type: Some(self.communication_type),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+        }
+    }
 }
 
 impl Endpoint for CommunicationType {
@@ -40,7 +54,7 @@ impl Endpoint for CommunicationType {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
@@ -50,9 +64,11 @@ impl Endpoint for CommunicationType {
     }
 }
 
+impl Pageable for CommunicationType {}
+
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::paged::Page, api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -67,18 +83,32 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("senate_communication/communication_type/endpoint");
+
+        let endpoint = CommunicationType::builder()
+            .congress(117_u8)
+            .communication_type(SenateCommunicationType::Ec)
+            .build()
+            .unwrap();
+
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        assert!(!page.items().is_empty());
+    }
 
+    #[test]
+    fn endpoint_url() {
         let endpoint = CommunicationType::builder()
             .congress(117_u8)
             .communication_type(SenateCommunicationType::Ec)
+            .offset(20)
+            .limit(10)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "senate-communication/117/ec?offset=20&limit=10"
+        );
     }
 }
@@ -0,0 +1,144 @@
+//! Per-query timeouts.
+
+use http::Method;
+use std::{borrow::Cow, future::Future, time::Duration};
+
+use crate::api::{
+    common::Format,
+    endpoint::{Endpoint, UrlBase},
+    error::ApiError,
+    paged::Pageable,
+    params::QueryParams,
+};
+
+/// Wraps an endpoint so that its queries fail with [`ApiError::Timeout`] after a deadline,
+/// regardless of the timeout configured on the client.
+#[derive(Debug, Clone)]
+pub struct Timeout<E> {
+    endpoint: E,
+    timeout: Duration,
+}
+
+/// Query an endpoint with the given timeout.
+pub fn timeout<E>(endpoint: E, timeout: Duration) -> Timeout<E> {
+    Timeout { endpoint, timeout }
+}
+
+impl<E> Endpoint for Timeout<E>
+where
+    E: Endpoint,
+{
+    fn method(&self) -> Method {
+        self.endpoint.method()
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.endpoint.endpoint()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        self.endpoint.url_base()
+    }
+
+    fn format(&self) -> Option<Format> {
+        self.endpoint.format()
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        Some(self.timeout)
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        self.endpoint.parameters()
+    }
+}
+
+impl<E> Pageable for Timeout<E> where E: Pageable {}
+
+/// Runs a query of the endpoint, failing once the timeout, if any, elapses.
+pub(crate) async fn with_timeout<F, T, E>(
+    timeout: Option<Duration>,
+    endpoint: &str,
+    query: F,
+) -> Result<T, ApiError<E>>
+where
+    F: Future<Output = Result<T, ApiError<E>>>,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let Some(timeout) = timeout else {
+        return query.await;
+    };
+
+    tokio::time::timeout(timeout, query)
+        .await
+        .unwrap_or_else(|_| {
+            Err(ApiError::Timeout {
+                endpoint: endpoint.into(),
+                timeout,
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{self, Query, bill},
+        test_util::{MockClient, MockResponse},
+    };
+
+    use super::*;
+
+    /// Answers `bill/117` after 50ms, or times out after `timeout`.
+    fn client(timeout: Duration) -> MockClient {
+        let client = MockClient::new().with_timeout(timeout);
+        client.expect(
+            MockResponse::builder()
+                .endpoint("bill/117")
+                .body(serde_json::json!({}))
+                .delay(Duration::from_millis(50))
+                .build()
+                .unwrap(),
+        );
+        client
+    }
+
+    fn endpoint() -> bill::Congress {
+        bill::Congress::builder().congress(117_u8).build().unwrap()
+    }
+
+    #[test]
+    fn overrides_timeout() {
+        let endpoint = endpoint();
+        assert_eq!(endpoint.timeout(), None);
+
+        let endpoint = api::timeout(endpoint, Duration::from_secs(1));
+        assert_eq!(endpoint.timeout(), Some(Duration::from_secs(1)));
+        assert_eq!(endpoint.endpoint(), "bill/117");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn client_timeout() {
+        let client = client(Duration::from_millis(5));
+
+        let err = Query::<serde_json::Value, _>::query(&endpoint(), &client)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            &err,
+            ApiError::Timeout { endpoint, timeout }
+                if endpoint == "bill/117" && *timeout == Duration::from_millis(5)
+        ));
+        assert_eq!(err.to_string(), "query of bill/117 timed out after 5ms");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn query_timeout_overrides_client() {
+        let client = client(Duration::from_millis(5));
+        let endpoint = api::timeout(endpoint(), Duration::from_secs(5));
+
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(res, serde_json::json!({}));
+    }
+}
@@ -1,3 +1,6 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
@@ -9,7 +12,7 @@ use crate::{api::endpoint::Endpoint, api::params::QueryParams};
 #[builder(setter(strip_option))]
 pub struct TreatyNumber {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     treaty_number: u32,
 }
@@ -18,6 +21,14 @@ impl TreatyNumber {
     pub fn builder() -> TreatyNumberBuilder {
         TreatyNumberBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> TreatyNumberBuilder {
+        TreatyNumberBuilder {
+            congress: Some(self.congress),
+            treaty_number: Some(self.treaty_number),
+        }
+    }
 }
 
 impl Endpoint for TreatyNumber {
@@ -29,14 +40,14 @@ impl Endpoint for TreatyNumber {
         format!("treaty/{}/{}", self.congress, self.treaty_number).into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         QueryParams::default()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{api::common::Format, api::query::Query, auth::Auth, cdg::Cdg};
+    use crate::{api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -51,18 +62,27 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("treaty/treaty_number/endpoint");
+
+        let endpoint = TreatyNumber::builder()
+            .congress(117_u8)
+            .treaty_number(3_u32)
+            .build()
+            .unwrap();
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
 
+        assert!(res["treaty"].is_object());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = TreatyNumber::builder()
             .congress(117_u8)
             .treaty_number(3_u32)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(crate::test::endpoint_url(&endpoint), "treaty/117/3");
     }
 }
@@ -1,15 +1,18 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::params::QueryParams};
+use crate::{api::endpoint::Endpoint, api::paged::Pageable, api::params::QueryParams};
 
-/// Represents the /treaty/:congress/:treatyNumber/:treatySuffix/actions endpoint.
+/// Represents the /treaty/:congress/:treatyNumber/actions endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct TreatyNumberActions {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     treaty_number: u32,
     #[builder(default)]
@@ -22,6 +25,16 @@ impl TreatyNumberActions {
     pub fn builder() -> TreatyNumberActionsBuilder {
         TreatyNumberActionsBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> TreatyNumberActionsBuilder {
+        TreatyNumberActionsBuilder {
+            congress: Some(self.congress),
+            treaty_number: Some(self.treaty_number),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+        }
+    }
 }
 
 impl Endpoint for TreatyNumberActions {
@@ -33,7 +46,7 @@ impl Endpoint for TreatyNumberActions {
         format!("treaty/{}/{}/actions", self.congress, self.treaty_number).into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
@@ -43,13 +56,11 @@ impl Endpoint for TreatyNumberActions {
     }
 }
 
+impl Pageable for TreatyNumberActions {}
+
 #[cfg(test)]
 mod tests {
-    use crate::{
-        api::{common::Format, query::Query},
-        auth::Auth,
-        cdg::Cdg,
-    };
+    use crate::{api::paged::Page, api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -64,18 +75,32 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("treaty/treaty_number_actions/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = TreatyNumberActions::builder()
+            .congress(117_u8)
+            .treaty_number(3_u32)
+            .build()
+            .unwrap();
+
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
 
+        assert!(!page.items().is_empty());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = TreatyNumberActions::builder()
             .congress(117_u8)
             .treaty_number(3_u32)
+            .offset(20)
+            .limit(10)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "treaty/117/3/actions?offset=20&limit=10"
+        );
     }
 }
@@ -1,3 +1,6 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
@@ -9,7 +12,7 @@ use crate::{api::endpoint::Endpoint, api::params::QueryParams};
 #[builder(setter(strip_option))]
 pub struct TreatySuffix<'a> {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     treaty_number: u32,
     #[builder(setter(into))]
@@ -20,6 +23,15 @@ impl<'a> TreatySuffix<'a> {
     pub fn builder() -> TreatySuffixBuilder<'a> {
         TreatySuffixBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> TreatySuffixBuilder<'a> {
+        TreatySuffixBuilder {
+            congress: Some(self.congress),
+            treaty_number: Some(self.treaty_number),
+            treaty_suffix: Some(self.treaty_suffix.clone()),
+        }
+    }
 }
 
 impl Endpoint for TreatySuffix<'_> {
@@ -35,18 +47,14 @@ impl Endpoint for TreatySuffix<'_> {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         QueryParams::default()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{
-        api::{common::Format, query::Query},
-        auth::Auth,
-        cdg::Cdg,
-    };
+    use crate::{api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -62,12 +70,22 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("treaty/treaty_suffix/endpoint");
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let endpoint = TreatySuffix::builder()
+            .congress(114_u8)
+            .treaty_number(13_u32)
+            .treaty_suffix("A")
+            .build()
+            .unwrap();
+
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
 
+        assert!(res["treaty"].is_object());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = TreatySuffix::builder()
             .congress(114_u8)
             .treaty_number(13_u32)
@@ -75,6 +93,6 @@ mod tests {
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(crate::test::endpoint_url(&endpoint), "treaty/114/13/A");
     }
 }
@@ -1,15 +1,18 @@
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+
 use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::endpoint::Endpoint, api::params::QueryParams};
+use crate::{api::endpoint::Endpoint, api::paged::Pageable, api::params::QueryParams};
 
 /// Represents the /treaty/:congress/:treatyNumber/:treatySuffix/actions endpoint.
 #[derive(Debug, Clone, Builder)]
 #[builder(setter(strip_option))]
 pub struct TreatySuffixActions<'a> {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     treaty_number: u32,
     #[builder(setter(into))]
@@ -24,6 +27,17 @@ impl<'a> TreatySuffixActions<'a> {
     pub fn builder() -> TreatySuffixActionsBuilder<'a> {
         TreatySuffixActionsBuilder::default()
     }
+
+    /// A builder with the parameters of this endpoint, e.g. to change some of them.
+    pub fn to_builder(&self) -> TreatySuffixActionsBuilder<'a> {
+        TreatySuffixActionsBuilder {
+            congress: Some(self.congress),
+            treaty_number: Some(self.treaty_number),
+            treaty_suffix: Some(self.treaty_suffix.clone()),
+            offset: Some(self.offset),
+            limit: Some(self.limit),
+        }
+    }
 }
 
 impl Endpoint for TreatySuffixActions<'_> {
@@ -39,7 +53,7 @@ impl Endpoint for TreatySuffixActions<'_> {
         .into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params.push_opt("offset", self.offset);
@@ -49,13 +63,11 @@ impl Endpoint for TreatySuffixActions<'_> {
     }
 }
 
+impl Pageable for TreatySuffixActions<'_> {}
+
 #[cfg(test)]
 mod tests {
-    use crate::{
-        api::{common::Format, query::Query},
-        auth::Auth,
-        cdg::Cdg,
-    };
+    use crate::{api::paged::Page, api::query::Query, test::cassette::Cassette};
 
     use super::*;
 
@@ -71,19 +83,34 @@ mod tests {
 
     #[tokio::test]
     async fn endpoint() {
-        dotenvy::dotenv().unwrap();
+        let client = Cassette::new("treaty/treaty_suffix_actions/endpoint");
+
+        let endpoint = TreatySuffixActions::builder()
+            .congress(114_u8)
+            .treaty_number(13_u32)
+            .treaty_suffix("A")
+            .build()
+            .unwrap();
 
-        let auth = Auth::Token(dotenvy::var("CDG_API_KEY").unwrap());
-        let req_client = reqwest::Client::new();
-        let client = Cdg::new(auth, req_client, Format::Json).unwrap();
+        let page: Page<serde_json::Value> = endpoint.query(&client).await.unwrap();
 
+        assert!(!page.items().is_empty());
+    }
+
+    #[test]
+    fn endpoint_url() {
         let endpoint = TreatySuffixActions::builder()
             .congress(114_u8)
             .treaty_number(13_u32)
             .treaty_suffix("A")
+            .offset(20)
+            .limit(10)
             .build()
             .unwrap();
 
-        let _res: serde_json::Value = endpoint.query(&client).await.unwrap();
+        assert_eq!(
+            crate::test::endpoint_url(&endpoint),
+            "treaty/114/13/A/actions?offset=20&limit=10"
+        );
     }
 }
@@ -1,6 +1,424 @@
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use bytes::Bytes;
+use http::{HeaderValue, Request, Response, StatusCode, request::Parts};
+use thiserror::Error;
+use url::Url;
+
+use crate::{
+    api::REDACTED,
+    middleware::{Flow, Middleware},
+    rate_limit::RATE_LIMIT_REMAINING_HEADER,
+    retry::retry_after,
+};
+
+/// The header carrying the API key in [`Auth::Token`] mode.
+pub const API_KEY_HEADER: &str = "x-api-key";
+
 /// The different ways to authenticate with the congress.gov
-/// API. Currently, only token-based authentication exists.
-#[derive(Debug, Clone)]
+/// API.
+///
+/// The key is never included in the `Debug` output.
+#[derive(Clone, PartialEq, Eq)]
 pub enum Auth {
+    /// Send the key in the `X-Api-Key` header, keeping it out of URLs.
     Token(String),
+    /// Send the key as the `api_key` query parameter.
+    QueryParam(String),
+    /// Send a key from a pool in the `X-Api-Key` header, rotating to the
+    /// next key when one runs out of quota.
+    Pool(KeyPool),
+}
+
+/// Identifies a key of a [`KeyPool`] by its position in the pool.
+///
+/// The key which served a request is attached to the response as an
+/// extension, so it can be read from the response of a
+/// [`RawQuery`](crate::api::RawQuery).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyId(pub usize);
+
+/// The usage of a key of a [`KeyPool`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyUsage {
+    /// The key the usage is for.
+    pub id: KeyId,
+    /// The number of requests sent with the key.
+    pub requests: u64,
+    /// The number of requests rejected with `429 Too Many Requests`.
+    pub rate_limited: u64,
+    /// The remaining hourly budget last reported by the API, if any.
+    pub remaining: Option<u32>,
+    /// Whether the key is resting after running out of quota.
+    pub exhausted: bool,
+}
+
+#[derive(Debug)]
+struct PoolKey {
+    key: String,
+    requests: u64,
+    rate_limited: u64,
+    remaining: Option<u32>,
+    exhausted_until: Option<Instant>,
+}
+
+impl PoolKey {
+    fn is_exhausted(&self, now: Instant) -> bool {
+        self.exhausted_until.is_some_and(|until| until > now)
+    }
+}
+
+#[derive(Debug)]
+struct PoolState {
+    keys: Vec<PoolKey>,
+    current: usize,
+    cooldown: Duration,
+}
+
+/// Errors which may occur when creating a [`KeyPool`].
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum KeyPoolError {
+    /// A pool without keys would send requests without authentication.
+    #[error("a key pool needs at least one key")]
+    Empty,
+}
+
+/// A pool of API keys shared across clones.
+///
+/// Requests use the current key until it runs out of quota, which is
+/// detected from a `429 Too Many Requests` response or an
+/// `X-RateLimit-Remaining` header of zero. The key then rests for the
+/// cooldown, or the `Retry-After` duration if the API sends one, and the
+/// pool moves on to the next key. When every key is resting, the key which
+/// recovers first is used.
+#[derive(Clone)]
+pub struct KeyPool {
+    state: Arc<Mutex<PoolState>>,
+}
+
+impl KeyPool {
+    /// The time a key rests after running out of quota, matching the
+    /// hourly window of the API.
+    pub const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60 * 60);
+
+    /// Creates a pool of the given keys, starting with the first one.
+    ///
+    /// Fails if no keys are given.
+    pub fn new<I, K>(keys: I) -> Result<Self, KeyPoolError>
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<String>,
+    {
+        let keys: Vec<_> = keys
+            .into_iter()
+            .map(|key| PoolKey {
+                key: key.into(),
+                requests: 0,
+                rate_limited: 0,
+                remaining: None,
+                exhausted_until: None,
+            })
+            .collect();
+        if keys.is_empty() {
+            return Err(KeyPoolError::Empty);
+        }
+
+        Ok(KeyPool {
+            state: Arc::new(Mutex::new(PoolState {
+                keys,
+                current: 0,
+                cooldown: Self::DEFAULT_COOLDOWN,
+            })),
+        })
+    }
+
+    /// Sets the time a key rests after running out of quota.
+    pub fn with_cooldown(self, cooldown: Duration) -> Self {
+        self.state.lock().unwrap().cooldown = cooldown;
+        self
+    }
+
+    /// The number of keys in the pool.
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().keys.len()
+    }
+
+    /// Whether the pool has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The usage of every key, in pool order.
+    pub fn usage(&self) -> Vec<KeyUsage> {
+        let now = Instant::now();
+        let state = self.state.lock().unwrap();
+        state
+            .keys
+            .iter()
+            .enumerate()
+            .map(|(index, key)| KeyUsage {
+                id: KeyId(index),
+                requests: key.requests,
+                rate_limited: key.rate_limited,
+                remaining: key.remaining,
+                exhausted: key.is_exhausted(now),
+            })
+            .collect()
+    }
+
+    /// Picks the key for the next request and counts the request.
+    fn acquire(&self) -> (KeyId, String) {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        let len = state.keys.len();
+
+        let available = (0..len)
+            .map(|offset| (state.current + offset) % len)
+            .find(|&index| !state.keys[index].is_exhausted(now));
+        let index = available.unwrap_or_else(|| {
+            (0..len)
+                .min_by_key(|&index| state.keys[index].exhausted_until)
+                .unwrap_or_default()
+        });
+
+        state.current = index;
+        let key = &mut state.keys[index];
+        key.requests += 1;
+        (KeyId(index), key.key.clone())
+    }
+
+    /// Records the response to a request made with the key, resting the
+    /// key if it ran out of quota.
+    fn record(&self, id: KeyId, response: &Response<Bytes>) {
+        let remaining = response
+            .headers()
+            .get(RATE_LIMIT_REMAINING_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u32>().ok());
+        let rate_limited = response.status() == StatusCode::TOO_MANY_REQUESTS;
+
+        let mut state = self.state.lock().unwrap();
+        let cooldown = state.cooldown;
+        let len = state.keys.len();
+        let Some(key) = state.keys.get_mut(id.0) else {
+            return;
+        };
+        if remaining.is_some() {
+            key.remaining = remaining;
+        }
+        if rate_limited {
+            key.rate_limited += 1;
+        }
+        if rate_limited || remaining == Some(0) {
+            let rest = retry_after(response.headers()).unwrap_or(cooldown);
+            key.exhausted_until = Some(Instant::now() + rest);
+            if state.current == id.0 {
+                state.current = (id.0 + 1) % len;
+            }
+        }
+    }
+}
+
+impl PartialEq for KeyPool {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl Eq for KeyPool {}
+
+impl fmt::Debug for KeyPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyPool")
+            .field("keys", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Auth::Token(_) => f.debug_tuple("Token").field(&REDACTED).finish(),
+            Auth::QueryParam(_) => f.debug_tuple("QueryParam").field(&REDACTED).finish(),
+            Auth::Pool(pool) => f.debug_tuple("Pool").field(pool).finish(),
+        }
+    }
+}
+
+fn set_header(request: &mut Request<Vec<u8>>, key: &str) {
+    // A key which is not a valid header value is left out, and the API
+    // rejects the request as unauthenticated.
+    if let Ok(mut value) = HeaderValue::from_str(key) {
+        value.set_sensitive(true);
+        request.headers_mut().insert(API_KEY_HEADER, value);
+    }
+}
+
+/// Adds the API key to requests which do not carry one yet.
+///
+/// [`Cdg`](crate::Cdg) applies its auth as this layer when sending each
+/// attempt of a request, so URLs seen by the layers above it never contain
+/// the key and a retried request may use another key of a pool.
+impl Middleware for Auth {
+    fn before(&self, request: &mut Request<Vec<u8>>) -> Flow {
+        if request.headers().contains_key(API_KEY_HEADER) {
+            return Flow::Continue;
+        }
+
+        match self {
+            Auth::Token(key) => set_header(request, key),
+            Auth::QueryParam(key) => {
+                let Ok(mut url) = Url::parse(&request.uri().to_string()) else {
+                    return Flow::Continue;
+                };
+                if url.query_pairs().any(|(name, _)| name == "api_key") {
+                    return Flow::Continue;
+                }
+                url.query_pairs_mut().append_pair("api_key", key);
+                if let Ok(uri) = url.as_str().parse() {
+                    *request.uri_mut() = uri;
+                }
+            }
+            Auth::Pool(pool) => {
+                let (id, key) = pool.acquire();
+                set_header(request, &key);
+                request.extensions_mut().insert(id);
+            }
+        }
+        Flow::Continue
+    }
+
+    /// Tracks the usage of pooled keys and attaches the [`KeyId`] which
+    /// served the request to the response.
+    fn after(&self, request: &Parts, response: &mut Response<Bytes>) {
+        if let (Auth::Pool(pool), Some(&id)) = (self, request.extensions.get::<KeyId>()) {
+            pool.record(id, response);
+            response.extensions_mut().insert(id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> Request<Vec<u8>> {
+        Request::get("https://api.congress.gov/v3/bill?format=json")
+            .body(Vec::new())
+            .unwrap()
+    }
+
+    #[test]
+    fn debug_is_redacted() {
+        let auth = Auth::Token("SECRET".into());
+
+        assert_eq!(format!("{:?}", auth), r#"Token("REDACTED")"#);
+        assert!(!format!("{:?}", Auth::QueryParam("SECRET".into())).contains("SECRET"));
+    }
+
+    #[test]
+    fn token_is_sent_in_header() {
+        let mut request = request();
+
+        Auth::Token("SECRET".into()).before(&mut request);
+
+        assert_eq!(request.headers()[API_KEY_HEADER], "SECRET");
+        assert!(request.headers()[API_KEY_HEADER].is_sensitive());
+        assert_eq!(
+            request.uri(),
+            "https://api.congress.gov/v3/bill?format=json"
+        );
+        assert!(!format!("{:?}", request).contains("SECRET"));
+    }
+
+    #[test]
+    fn query_param_is_appended() {
+        let mut request = request();
+
+        Auth::QueryParam("SECRET".into()).before(&mut request);
+        Auth::QueryParam("OTHER".into()).before(&mut request);
+
+        assert!(!request.headers().contains_key(API_KEY_HEADER));
+        assert_eq!(
+            request.uri(),
+            "https://api.congress.gov/v3/bill?format=json&api_key=SECRET"
+        );
+    }
+
+    fn response(status: StatusCode, remaining: &'static str) -> Response<Bytes> {
+        Response::builder()
+            .status(status)
+            .header(RATE_LIMIT_REMAINING_HEADER, remaining)
+            .body(Bytes::new())
+            .unwrap()
+    }
+
+    /// Sends a request with the auth, returning the key and the key id
+    /// attached to the response.
+    fn send(auth: &Auth, mut rsp: Response<Bytes>) -> (String, Option<KeyId>) {
+        let mut request = request();
+        auth.before(&mut request);
+        let key = request.headers()[API_KEY_HEADER].to_str().unwrap().into();
+        let (parts, _) = request.into_parts();
+        auth.after(&parts, &mut rsp);
+        (key, rsp.extensions().get::<KeyId>().copied())
+    }
+
+    #[test]
+    fn pool_rotates_on_exhaustion() {
+        let pool = KeyPool::new(["A", "B"]).unwrap();
+        let auth = Auth::Pool(pool.clone());
+
+        assert_eq!(
+            send(&auth, response(StatusCode::OK, "10")),
+            ("A".into(), Some(KeyId(0)))
+        );
+        assert_eq!(
+            send(&auth, response(StatusCode::TOO_MANY_REQUESTS, "0")),
+            ("A".into(), Some(KeyId(0)))
+        );
+        assert_eq!(
+            send(&auth, response(StatusCode::OK, "0")),
+            ("B".into(), Some(KeyId(1)))
+        );
+
+        let usage = pool.usage();
+        assert_eq!(usage[0].requests, 2);
+        assert_eq!(usage[0].rate_limited, 1);
+        assert_eq!(usage[0].remaining, Some(0));
+        assert!(usage[0].exhausted);
+        assert_eq!(usage[1].requests, 1);
+        assert!(usage[1].exhausted);
+    }
+
+    #[test]
+    fn pool_recovers_after_cooldown() {
+        let pool = KeyPool::new(["A", "B"])
+            .unwrap()
+            .with_cooldown(Duration::ZERO);
+        let auth = Auth::Pool(pool.clone());
+
+        send(&auth, response(StatusCode::TOO_MANY_REQUESTS, "0"));
+        assert_eq!(send(&auth, response(StatusCode::OK, "5")).0, "B");
+        assert!(pool.usage().iter().all(|usage| !usage.exhausted));
+    }
+
+    #[test]
+    fn empty_pool_is_rejected() {
+        let err = KeyPool::new(Vec::<String>::new()).unwrap_err();
+
+        assert!(matches!(err, KeyPoolError::Empty));
+    }
+
+    #[test]
+    fn pool_debug_is_redacted() {
+        let auth = Auth::Pool(KeyPool::new(["SECRET"]).unwrap());
+
+        assert_eq!(format!("{:?}", auth), "Pool(KeyPool { keys: 1, .. })");
+    }
 }
@@ -0,0 +1,80 @@
+//! HTTP backends used by [`Cdg`](crate::Cdg) to perform requests.
+//!
+//! The backend is chosen through cargo features:
+//!
+//! - `reqwest` (default): uses a `reqwest::Client`
+//! - `hyper`: uses a `hyper_util` legacy client with TLS support
+//! - `ureq`: uses a blocking `ureq::Agent` on tokio's blocking thread pool
+//!
+//! When several backends are enabled, the default backend is the first
+//! available in the order above. Any backend may still be used explicitly
+//! via [`CdgBuilder::build_with`](crate::CdgBuilder::build_with).
+
+use std::future::Future;
+
+use bytes::Bytes;
+use http::{Request, Response};
+
+use crate::cdg::CdgError;
+
+#[cfg(feature = "hyper")]
+mod hyper;
+#[cfg(feature = "reqwest")]
+mod reqwest;
+#[cfg(feature = "ureq")]
+mod ureq;
+
+#[cfg(feature = "hyper")]
+pub use self::hyper::{HyperClient, hyper_client};
+#[cfg(feature = "ureq")]
+pub use self::ureq::ureq_agent;
+
+/// The backend used by [`Cdg`](crate::Cdg) unless another is given.
+#[cfg(feature = "reqwest")]
+pub type DefaultBackend = ::reqwest::Client;
+
+/// The backend used by [`Cdg`](crate::Cdg) unless another is given.
+#[cfg(all(not(feature = "reqwest"), feature = "hyper"))]
+pub type DefaultBackend = HyperClient;
+
+/// The backend used by [`Cdg`](crate::Cdg) unless another is given.
+#[cfg(all(not(feature = "reqwest"), not(feature = "hyper"), feature = "ureq"))]
+pub type DefaultBackend = ::ureq::Agent;
+
+/// Creates the [`DefaultBackend`] with the settings [`Cdg`](crate::Cdg)
+/// expects of it, e.g. to wrap it before passing it to
+/// [`CdgBuilder::build_with`](crate::CdgBuilder::build_with).
+pub fn default_backend() -> DefaultBackend {
+    #[cfg(feature = "reqwest")]
+    return DefaultBackend::default();
+    #[cfg(all(not(feature = "reqwest"), feature = "hyper"))]
+    return hyper_client();
+    #[cfg(all(not(feature = "reqwest"), not(feature = "hyper"), feature = "ureq"))]
+    return ureq_agent();
+}
+
+/// A trait representing an HTTP client able to send a single request.
+pub trait HttpBackend: Send + Sync {
+    /// Sends the request, returning the response with its body fully read.
+    ///
+    /// Unsuccessful statuses are not errors; only failures to communicate
+    /// with the server are.
+    fn send(
+        &self,
+        request: Request<Vec<u8>>,
+    ) -> impl Future<Output = Result<Response<Bytes>, CdgError>> + Send;
+
+    /// Whether an error returned by [`send`](Self::send) is transient, such
+    /// as a refused connection or a timeout, and may succeed if retried.
+    fn is_transient(&self, error: &CdgError) -> bool;
+}
+
+/// Copies a buffered request so that it may be sent again.
+pub(crate) fn clone_request(request: &Request<Vec<u8>>) -> Request<Vec<u8>> {
+    let mut clone = Request::new(request.body().clone());
+    *clone.method_mut() = request.method().clone();
+    *clone.uri_mut() = request.uri().clone();
+    *clone.version_mut() = request.version();
+    *clone.headers_mut() = request.headers().clone();
+    clone
+}
@@ -0,0 +1,58 @@
+use bytes::Bytes;
+use http::{Request, Response};
+use http_body_util::{BodyExt, Full};
+use hyper_tls::HttpsConnector;
+use hyper_util::{
+    client::legacy::{Client, connect::HttpConnector},
+    rt::TokioExecutor,
+};
+
+use crate::{backend::HttpBackend, cdg::CdgError};
+
+/// A hyper client able to connect to the congress.gov API over TLS.
+pub type HyperClient = Client<HttpsConnector<HttpConnector>, Full<Bytes>>;
+
+/// Creates a hyper client with TLS support running on tokio.
+pub fn hyper_client() -> HyperClient {
+    Client::builder(TokioExecutor::new()).build(HttpsConnector::new())
+}
+
+impl HttpBackend for HyperClient {
+    async fn send(&self, request: Request<Vec<u8>>) -> Result<Response<Bytes>, CdgError> {
+        let request = request.map(|body| Full::new(Bytes::from(body)));
+        let rsp = self.request(request).await?;
+
+        let (parts, body) = rsp.into_parts();
+        let body = body.collect().await?.to_bytes();
+
+        Ok(Response::from_parts(parts, body))
+    }
+
+    fn is_transient(&self, error: &CdgError) -> bool {
+        match error {
+            CdgError::Hyper { source } => source.is_connect(),
+            CdgError::HyperBody { source } => {
+                source.is_incomplete_message() || source.is_closed() || source.is_timeout()
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn refused_connection_is_transient() {
+        // Nothing listens on the discard port.
+        let client = hyper_client();
+        let request = Request::get("http://127.0.0.1:9/v3/bill")
+            .body(Vec::new())
+            .unwrap();
+
+        let err = client.send(request).await.unwrap_err();
+
+        assert!(client.is_transient(&err));
+    }
+}
@@ -0,0 +1,76 @@
+use bytes::Bytes;
+use http::{Request, Response};
+
+use crate::{api::redact_url, backend::HttpBackend, cdg::CdgError};
+
+impl HttpBackend for reqwest::Client {
+    async fn send(&self, request: Request<Vec<u8>>) -> Result<Response<Bytes>, CdgError> {
+        let request: reqwest::Request = request.try_into().map_err(redact)?;
+        let rsp = self.execute(request).await.map_err(redact)?;
+
+        let mut http_rsp = Response::builder()
+            .status(rsp.status())
+            .version(rsp.version());
+        let headers = http_rsp.headers_mut().unwrap();
+        for (key, value) in rsp.headers() {
+            headers.insert(key, value.clone());
+        }
+
+        Ok(http_rsp.body(rsp.bytes().await.map_err(redact)?)?)
+    }
+
+    fn is_transient(&self, error: &CdgError) -> bool {
+        match error {
+            CdgError::Communication { source } => {
+                source.is_connect()
+                    || source.is_timeout()
+                    || source.is_request()
+                    || source.is_body()
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Redacts the API key from the URL carried by the error.
+fn redact(mut err: reqwest::Error) -> reqwest::Error {
+    if let Some(url) = err.url_mut() {
+        *url = redact_url(url);
+    }
+    err
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn refused_connection_is_transient() {
+        // Nothing listens on the discard port.
+        let client = reqwest::Client::new();
+        let request = Request::get("http://127.0.0.1:9/v3/bill")
+            .body(Vec::new())
+            .unwrap();
+
+        let err = client.send(request).await.unwrap_err();
+
+        assert!(client.is_transient(&err));
+    }
+
+    #[tokio::test]
+    async fn errors_are_redacted() {
+        let client = reqwest::Client::new();
+        let request = Request::get("http://127.0.0.1:9/v3/bill?api_key=SECRET")
+            .body(Vec::new())
+            .unwrap();
+
+        let err = client.send(request).await.unwrap_err();
+
+        let CdgError::Communication { source } = &err else {
+            panic!("unexpected error: {:?}", err);
+        };
+        assert_eq!(source.url().unwrap().query(), Some("api_key=REDACTED"));
+        assert!(!err.to_string().contains("SECRET"));
+        assert!(!format!("{:?}", err).contains("SECRET"));
+    }
+}
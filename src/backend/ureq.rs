@@ -0,0 +1,61 @@
+use bytes::Bytes;
+use http::{Request, Response};
+use ureq::Agent;
+
+use crate::{backend::HttpBackend, cdg::CdgError};
+
+/// Creates a ureq agent which returns unsuccessful statuses as responses
+/// rather than errors, as expected by [`Cdg`](crate::Cdg).
+pub fn ureq_agent() -> Agent {
+    Agent::config_builder()
+        .http_status_as_error(false)
+        .build()
+        .into()
+}
+
+/// Requests are performed on tokio's blocking thread pool.
+///
+/// Agents should be created with [`ureq_agent`] so that error statuses are
+/// returned as responses.
+impl HttpBackend for Agent {
+    async fn send(&self, request: Request<Vec<u8>>) -> Result<Response<Bytes>, CdgError> {
+        let agent = self.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut rsp = agent.run(request)?;
+            let body = rsp.body_mut().read_to_vec()?;
+            let (parts, _) = rsp.into_parts();
+
+            Ok(Response::from_parts(parts, Bytes::from(body)))
+        })
+        .await?
+    }
+
+    fn is_transient(&self, error: &CdgError) -> bool {
+        matches!(
+            error,
+            CdgError::Ureq {
+                source: ureq::Error::Io(_)
+                    | ureq::Error::Timeout(_)
+                    | ureq::Error::ConnectionFailed
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn refused_connection_is_transient() {
+        // Nothing listens on the discard port.
+        let client = ureq_agent();
+        let request = Request::get("http://127.0.0.1:9/v3/bill")
+            .body(Vec::new())
+            .unwrap();
+
+        let err = client.send(request).await.unwrap_err();
+
+        assert!(client.is_transient(&err));
+    }
+}
@@ -0,0 +1,91 @@
+use std::{future::Future, sync::Arc};
+
+use tokio::runtime::{Builder, Runtime};
+
+use crate::{
+    api::BlockingClient,
+    backend::{DefaultBackend, HttpBackend},
+    cdg::{Cdg, CdgError},
+};
+
+/// A synchronous wrapper around [`Cdg`].
+///
+/// Requests are performed on a single-threaded runtime owned by the client,
+/// so callers do not need an async runtime of their own. As with other
+/// blocking HTTP clients, queries must not be made from within an async
+/// context. Clones share the same runtime.
+#[derive(Debug, Clone)]
+pub struct BlockingCdg<B = DefaultBackend> {
+    cdg: Cdg<B>,
+    runtime: Arc<Runtime>,
+}
+
+impl<B> BlockingCdg<B>
+where
+    B: HttpBackend,
+{
+    /// Creates a blocking client performing requests with the given Cdg.
+    pub fn new(cdg: Cdg<B>) -> Result<BlockingCdg<B>, CdgError> {
+        let runtime = Builder::new_current_thread().enable_all().build()?;
+
+        Ok(BlockingCdg {
+            cdg,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// The async client used to perform requests.
+    pub fn cdg(&self) -> &Cdg<B> {
+        &self.cdg
+    }
+}
+
+impl<B> BlockingClient for BlockingCdg<B>
+where
+    B: HttpBackend,
+{
+    type Client = Cdg<B>;
+
+    fn client(&self) -> &Cdg<B> {
+        &self.cdg
+    }
+
+    fn block_on<F>(&self, future: F) -> F::Output
+    where
+        F: Future,
+    {
+        self.runtime.block_on(future)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::{
+        api::{ApiError, BlockingQuery, congress},
+        auth::Auth,
+    };
+
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct CurrentCongress {}
+
+    #[test]
+    fn query_blocking() {
+        // Nothing listens on the discard port, so the request fails without
+        // leaving the machine.
+        let cdg = Cdg::builder()
+            .auth(Auth::Token("KEY".into()))
+            .base_url("http://127.0.0.1:9/v3/")
+            .build()
+            .unwrap();
+        let client = BlockingCdg::new(cdg).unwrap();
+        let endpoint = congress::CurrentCongress::builder().build().unwrap();
+
+        let res: Result<CurrentCongress, _> = endpoint.query_blocking(&client);
+
+        assert!(matches!(res, Err(ApiError::Client { .. })));
+    }
+}
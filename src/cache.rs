@@ -0,0 +1,705 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{
+        Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use bytes::Bytes;
+use derive_builder::Builder;
+use http::{Method, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::api::{ApiError, Client, common::Format};
+
+/// A response stored in a [`CacheStore`].
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    /// The HTTP status of the response.
+    pub status: StatusCode,
+    /// The response headers which are valid UTF-8.
+    pub headers: Vec<(String, String)>,
+    /// The response body.
+    pub body: Bytes,
+    /// When the response was received.
+    pub stored_at: SystemTime,
+}
+
+impl CachedResponse {
+    fn from_response(rsp: &Response<Bytes>) -> Self {
+        CachedResponse {
+            status: rsp.status(),
+            headers: rsp
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((name.as_str().into(), value.to_str().ok()?.into()))
+                })
+                .collect(),
+            body: rsp.body().clone(),
+            stored_at: SystemTime::now(),
+        }
+    }
+
+    /// Rebuilds the response, or `None` if a stored header is invalid.
+    fn to_response(&self) -> Option<Response<Bytes>> {
+        let mut rsp = Response::builder().status(self.status);
+        for (name, value) in &self.headers {
+            rsp = rsp.header(name, value);
+        }
+        rsp.body(self.body.clone()).ok()
+    }
+
+    fn age(&self) -> Duration {
+        SystemTime::now()
+            .duration_since(self.stored_at)
+            .unwrap_or_default()
+    }
+}
+
+/// Storage for cached responses, keyed on the normalized request URL.
+///
+/// Stores are best effort: a failure to read or write an entry is treated
+/// as a cache miss rather than failing the request.
+pub trait CacheStore: Send + Sync {
+    /// Returns the entry for the key, if any.
+    fn get(&self, key: &str) -> Option<CachedResponse>;
+    /// Stores an entry, replacing any previous entry for the key.
+    fn put(&self, key: &str, response: CachedResponse);
+    /// Removes the entry for the key.
+    fn remove(&self, key: &str);
+    /// Removes every entry.
+    fn clear(&self);
+}
+
+/// A cache held in memory for the lifetime of the client.
+#[derive(Debug, Default)]
+pub struct MemoryCache {
+    entries: Mutex<HashMap<String, CachedResponse>>,
+}
+
+impl MemoryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CacheStore for MemoryCache {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        self.entries.lock().unwrap().get(key).cloned()
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        self.entries.lock().unwrap().insert(key.into(), response);
+    }
+
+    fn remove(&self, key: &str) {
+        self.entries.lock().unwrap().remove(key);
+    }
+
+    fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
+/// A cache persisted to a directory, so that it survives restarts and can
+/// be shared between processes.
+///
+/// Every entry is stored as a `.json` metadata file and a `.body` file
+/// named after a hash of the key. Both are written to a temporary file
+/// first and renamed into place.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DiskEntry {
+    key: String,
+    status: u16,
+    headers: Vec<(String, String)>,
+    stored_at: u64,
+}
+
+impl DiskCache {
+    /// Creates a cache in the given directory, creating it if needed.
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(DiskCache { dir })
+    }
+
+    /// The directory the cache is stored in.
+    pub fn dir(&self) -> &PathBuf {
+        &self.dir
+    }
+
+    fn paths(&self, key: &str) -> (PathBuf, PathBuf) {
+        // FNV-1a, which unlike the std hasher is stable across releases.
+        let hash = key.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+        let name = format!("{:016x}", hash);
+        (
+            self.dir.join(format!("{}.json", name)),
+            self.dir.join(format!("{}.body", name)),
+        )
+    }
+
+    fn read(&self, key: &str) -> Option<CachedResponse> {
+        let (meta, body) = self.paths(key);
+        let entry: DiskEntry = serde_json::from_slice(&fs::read(meta).ok()?).ok()?;
+        // Guard against hash collisions.
+        if entry.key != key {
+            return None;
+        }
+        Some(CachedResponse {
+            status: StatusCode::from_u16(entry.status).ok()?,
+            headers: entry.headers,
+            body: fs::read(body).ok()?.into(),
+            stored_at: UNIX_EPOCH + Duration::from_secs(entry.stored_at),
+        })
+    }
+
+    fn write(&self, key: &str, response: &CachedResponse) -> io::Result<()> {
+        let (meta, body) = self.paths(key);
+        let entry = DiskEntry {
+            key: key.into(),
+            status: response.status.as_u16(),
+            headers: response.headers.clone(),
+            stored_at: response
+                .stored_at
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+        };
+        // Write the body before the metadata which refers to it, each by
+        // renaming a complete file into place so that other processes never
+        // read a partial entry.
+        self.write_atomic(&body, &response.body)?;
+        self.write_atomic(&meta, &serde_json::to_vec(&entry)?)
+    }
+
+    fn write_atomic(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let tmp = path.with_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed),
+        ));
+        fs::write(&tmp, contents)
+            .and_then(|()| fs::rename(&tmp, path))
+            .inspect_err(|_| {
+                let _ = fs::remove_file(&tmp);
+            })
+    }
+}
+
+impl CacheStore for DiskCache {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        self.read(key)
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        // Failing to persist an entry only costs a request later on.
+        let _ = self.write(key, &response);
+    }
+
+    fn remove(&self, key: &str) {
+        let (meta, body) = self.paths(key);
+        let _ = fs::remove_file(meta);
+        let _ = fs::remove_file(body);
+    }
+
+    fn clear(&self) {
+        let Ok(entries) = fs::read_dir(&self.dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if matches!(
+                path.extension().and_then(|ext| ext.to_str()),
+                Some("json" | "body" | "tmp")
+            ) {
+                let _ = fs::remove_file(path);
+            }
+        }
+    }
+}
+
+/// How long responses are cached, per resource.
+///
+/// A TTL set for a resource path such as `bill` applies to every endpoint
+/// below it, e.g. `bill/117/hr/3076`, unless a longer path has its own
+/// TTL. Responses with a TTL of zero are not cached.
+#[derive(Debug, Clone, Builder)]
+#[builder(default)]
+pub struct CachePolicy {
+    /// The TTL for resources without a more specific one.
+    default_ttl: Duration,
+    /// The TTLs for specific resource paths.
+    #[builder(setter(custom))]
+    ttls: Vec<(String, Duration)>,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        CachePolicy {
+            default_ttl: Duration::from_secs(60 * 60),
+            // The bound record is published once and never revised.
+            ttls: vec![(
+                "bound-congressional-record".into(),
+                Duration::from_secs(30 * 24 * 60 * 60),
+            )],
+        }
+    }
+}
+
+impl CachePolicyBuilder {
+    /// Sets the TTL for a resource path, e.g. `bill` or
+    /// `bound-congressional-record`.
+    pub fn ttl(&mut self, resource: impl Into<String>, ttl: Duration) -> &mut Self {
+        let resource = resource.into().trim_matches('/').to_string();
+        let ttls = self.ttls.get_or_insert_with(|| CachePolicy::default().ttls);
+        ttls.retain(|(path, _)| *path != resource);
+        ttls.push((resource, ttl));
+        self
+    }
+}
+
+impl CachePolicy {
+    pub fn builder() -> CachePolicyBuilder {
+        CachePolicyBuilder::default()
+    }
+
+    /// The TTL for an endpoint path, relative to the API root.
+    pub fn ttl(&self, endpoint: &str) -> Duration {
+        let endpoint = endpoint.trim_matches('/');
+        self.ttls
+            .iter()
+            .filter(|(path, _)| {
+                endpoint
+                    .strip_prefix(path.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            })
+            .max_by_key(|(path, _)| path.len())
+            .map_or(self.default_ttl, |(_, ttl)| *ttl)
+    }
+}
+
+/// Controls how a single query interacts with the cache.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CacheMode {
+    /// Use a fresh cached response if there is one, otherwise fetch and
+    /// cache the response.
+    #[default]
+    Default,
+    /// Neither read nor write the cache.
+    Bypass,
+    /// Ignore any cached response, then cache the fetched response.
+    Refresh,
+}
+
+/// A [`Client`] which caches successful `GET` responses of another client.
+///
+/// Responses are keyed on the request URL with its query parameters
+/// sorted and the `api_key` removed, so clients using different keys share
+/// entries. Errors are never cached.
+///
+/// ```rust,no_run
+/// # use congressdotgov_rs::{Auth, Cdg, Cached, CacheMode, CachePolicy, DiskCache};
+/// # use congressdotgov_rs::api::{Query, bill};
+/// # use std::time::Duration;
+/// # tokio_test::block_on(async {
+/// let cdg = Cdg::builder().auth(Auth::Token("API_KEY".into())).build().unwrap();
+/// let policy = CachePolicy::builder()
+///     .ttl("bill", Duration::from_secs(10 * 60))
+///     .build()
+///     .unwrap();
+/// let client = Cached::with_store(cdg, DiskCache::new(".cache").unwrap(), policy);
+///
+/// let endpoint = bill::Congress::builder().congress(117_u8).build().unwrap();
+/// let cached: serde_json::Value = endpoint.query(&client).await.unwrap();
+/// let fresh: serde_json::Value = endpoint.query(&client.refresh()).await.unwrap();
+/// # })
+/// ```
+#[derive(Debug)]
+pub struct Cached<C, S = MemoryCache> {
+    client: C,
+    store: S,
+    policy: CachePolicy,
+}
+
+impl<C> Cached<C> {
+    /// Caches the responses of the client in memory.
+    pub fn new(client: C, policy: CachePolicy) -> Self {
+        Self::with_store(client, MemoryCache::new(), policy)
+    }
+}
+
+impl<C, S> Cached<C, S> {
+    /// Caches the responses of the client in the given store.
+    pub fn with_store(client: C, store: S, policy: CachePolicy) -> Self {
+        Cached {
+            client,
+            store,
+            policy,
+        }
+    }
+
+    /// The wrapped client.
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    /// The cache store.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// The cache policy.
+    pub fn policy(&self) -> &CachePolicy {
+        &self.policy
+    }
+
+    /// A client using the cache with the given mode, for use with a single
+    /// query.
+    pub fn with_mode(&self, mode: CacheMode) -> CacheControl<'_, C, S> {
+        CacheControl { cached: self, mode }
+    }
+
+    /// A client which neither reads nor writes the cache.
+    pub fn bypass(&self) -> CacheControl<'_, C, S> {
+        self.with_mode(CacheMode::Bypass)
+    }
+
+    /// A client which ignores cached responses but caches fresh ones.
+    pub fn refresh(&self) -> CacheControl<'_, C, S> {
+        self.with_mode(CacheMode::Refresh)
+    }
+}
+
+/// Normalizes a request URL into a cache key.
+fn cache_key(url: &Url) -> String {
+    let mut pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(key, _)| key != "api_key")
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+    pairs.sort();
+
+    let mut url = url.clone();
+    url.set_fragment(None);
+    url.set_query(None);
+    if !pairs.is_empty() {
+        url.query_pairs_mut().extend_pairs(pairs);
+    }
+    url.into()
+}
+
+impl<C, S> Cached<C, S>
+where
+    C: Client + Sync,
+    S: CacheStore,
+{
+    /// Removes the cached response for a URL, e.g. one returned in a
+    /// `url` field of a response.
+    pub fn invalidate(&self, url: &Url) {
+        self.store.remove(&cache_key(url));
+    }
+
+    fn ttl(&self, url: &Url) -> Duration {
+        let base = self.client.rest_endpoint("").ok();
+        let endpoint = base
+            .as_ref()
+            .and_then(|base| url.path().strip_prefix(base.path()))
+            .unwrap_or_else(|| url.path());
+        self.policy.ttl(endpoint)
+    }
+
+    async fn rest_with_mode(
+        &self,
+        mode: CacheMode,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<C::Error>> {
+        let url = request
+            .uri_ref()
+            .and_then(|uri| Url::parse(&uri.to_string()).ok());
+        let cacheable = request
+            .method_ref()
+            .is_none_or(|method| method == Method::GET);
+        let entry = match url {
+            Some(url) if cacheable && mode != CacheMode::Bypass => {
+                let ttl = self.ttl(&url);
+                (!ttl.is_zero()).then(|| (cache_key(&url), ttl))
+            }
+            _ => None,
+        };
+
+        if let Some((key, ttl)) = &entry
+            && mode == CacheMode::Default
+            && let Some(cached) = self.store.get(key)
+        {
+            if cached.age() < *ttl
+                && let Some(rsp) = cached.to_response()
+            {
+                return Ok(rsp);
+            }
+            // Expired or unreadable entries are refetched.
+            self.store.remove(key);
+        }
+
+        let rsp = self.client.rest(request, body).await?;
+        if let Some((key, _)) = &entry
+            && rsp.status().is_success()
+        {
+            self.store.put(key, CachedResponse::from_response(&rsp));
+        }
+
+        Ok(rsp)
+    }
+}
+
+impl<C, S> Client for Cached<C, S>
+where
+    C: Client + Sync,
+    S: CacheStore,
+{
+    type Error = C::Error;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.client.rest_endpoint(endpoint)
+    }
+
+    fn set_auth(&self, url: &mut Url) {
+        self.client.set_auth(url)
+    }
+
+    fn format(&self) -> Format {
+        self.client.format()
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.client.timeout()
+    }
+
+    async fn rest(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.rest_with_mode(CacheMode::Default, request, body).await
+    }
+}
+
+/// A [`Cached`] client with a [`CacheMode`] applied to its queries.
+#[derive(Debug)]
+pub struct CacheControl<'a, C, S> {
+    cached: &'a Cached<C, S>,
+    mode: CacheMode,
+}
+
+impl<C, S> Client for CacheControl<'_, C, S>
+where
+    C: Client + Sync,
+    S: CacheStore,
+{
+    type Error = C::Error;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.cached.rest_endpoint(endpoint)
+    }
+
+    fn set_auth(&self, url: &mut Url) {
+        self.cached.set_auth(url)
+    }
+
+    fn format(&self) -> Format {
+        self.cached.format()
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.cached.timeout()
+    }
+
+    async fn rest(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.cached.rest_with_mode(self.mode, request, body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        api::{Query, bill, bound_congressional_record},
+        test_util::{MockClient, MockResponse},
+    };
+
+    use super::*;
+
+    fn client() -> MockClient {
+        let client = MockClient::new();
+        client.expect(
+            MockResponse::builder()
+                .endpoint("bill/117")
+                .body(json!({ "bills": [] }))
+                .build()
+                .unwrap(),
+        );
+        client
+    }
+
+    async fn query<C: Client>(client: &C) -> serde_json::Value {
+        let endpoint = bill::Congress::builder().congress(117_u8).build().unwrap();
+        endpoint.query(client).await.unwrap()
+    }
+
+    #[test]
+    fn key_is_normalized() {
+        let a =
+            Url::parse("https://api.congress.gov/v3/bill?limit=5&api_key=A&format=json").unwrap();
+        let b =
+            Url::parse("https://api.congress.gov/v3/bill?format=json&api_key=B&limit=5").unwrap();
+
+        assert_eq!(cache_key(&a), cache_key(&b));
+        assert_eq!(
+            cache_key(&a),
+            "https://api.congress.gov/v3/bill?format=json&limit=5"
+        );
+    }
+
+    #[test]
+    fn ttl_per_resource() {
+        let policy = CachePolicy::builder()
+            .default_ttl(Duration::from_secs(60))
+            .ttl("bill", Duration::from_secs(10))
+            .ttl("bill/117/hr", Duration::from_secs(20))
+            .build()
+            .unwrap();
+
+        assert_eq!(policy.ttl("bill"), Duration::from_secs(10));
+        assert_eq!(policy.ttl("bill/118"), Duration::from_secs(10));
+        assert_eq!(policy.ttl("bill/117/hr/3076"), Duration::from_secs(20));
+        assert_eq!(policy.ttl("billing"), Duration::from_secs(60));
+        assert!(policy.ttl("bound-congressional-record/2024") > Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn serves_from_cache() {
+        let mock = client();
+        let client = Cached::new(mock.clone(), CachePolicy::default());
+
+        assert_eq!(query(&client).await, json!({ "bills": [] }));
+        assert_eq!(query(&client).await, json!({ "bills": [] }));
+
+        assert_eq!(mock.calls().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn bypass_and_refresh() {
+        let mock = client();
+        let client = Cached::new(mock.clone(), CachePolicy::default());
+
+        query(&client.bypass()).await;
+        query(&client).await;
+        assert_eq!(mock.calls().len(), 2);
+
+        query(&client.refresh()).await;
+        query(&client).await;
+        assert_eq!(mock.calls().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn expired_entries_are_refetched() {
+        let mock = client();
+        let policy = CachePolicy::builder()
+            .ttl("bill", Duration::ZERO)
+            .build()
+            .unwrap();
+        let client = Cached::new(mock.clone(), policy);
+
+        query(&client).await;
+        query(&client).await;
+
+        assert_eq!(mock.calls().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn errors_are_not_cached() {
+        let mock = MockClient::new();
+        let client = Cached::new(mock.clone(), CachePolicy::default());
+        let endpoint = bound_congressional_record::Year::builder()
+            .year(1990_u16)
+            .build()
+            .unwrap();
+
+        for _ in 0..2 {
+            Query::<serde_json::Value, _>::query(&endpoint, &client)
+                .await
+                .unwrap_err();
+        }
+
+        assert_eq!(mock.calls().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn invalid_entries_are_refetched() {
+        let mock = client();
+        let client = Cached::new(mock.clone(), CachePolicy::default());
+        let key = "https://api.congress.gov/v3/bill/117?format=json";
+        client.store().put(
+            key,
+            CachedResponse {
+                status: StatusCode::OK,
+                headers: vec![("bad header".into(), "value".into())],
+                body: Bytes::from_static(b"{}"),
+                stored_at: SystemTime::now(),
+            },
+        );
+
+        assert_eq!(query(&client).await, json!({ "bills": [] }));
+        assert_eq!(mock.calls().len(), 1);
+        let stored = client.store().get(key).unwrap();
+        assert!(stored.headers.iter().all(|(name, _)| name != "bad header"));
+    }
+
+    #[tokio::test]
+    async fn disk_cache_persists() {
+        let dir =
+            std::env::temp_dir().join(format!("congressdotgov_rs-cache-{}", std::process::id()));
+        let mock = client();
+
+        let client = Cached::with_store(
+            mock.clone(),
+            DiskCache::new(&dir).unwrap(),
+            CachePolicy::default(),
+        );
+        query(&client).await;
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+
+        let client = Cached::with_store(
+            mock.clone(),
+            DiskCache::new(&dir).unwrap(),
+            CachePolicy::default(),
+        );
+        assert_eq!(query(&client).await, json!({ "bills": [] }));
+        assert_eq!(mock.calls().len(), 1);
+
+        client.store().clear();
+        query(&client).await;
+        assert_eq!(mock.calls().len(), 2);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
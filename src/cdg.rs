@@ -1,14 +1,27 @@
+use std::time::Duration;
+
 use bytes::Bytes;
-use http::{Response, header::InvalidHeaderValue};
-use reqwest::{Client, Request};
+use derive_builder::{Builder, UninitializedFieldError};
+use http::{HeaderMap, Request, Response, header::InvalidHeaderValue};
 use thiserror::Error;
 use url::Url;
 
 use crate::{
-    api::{ApiError, common::Format},
+    api::{ApiError, common::Format, redact_str},
     auth::Auth,
+    backend::{DefaultBackend, HttpBackend, clone_request},
+    middleware::Middleware,
+    rate_limit::RateLimiter,
+    retry::{Attempts, RetryPolicy},
 };
 
+/// The base URL of the congress.gov v3 API.
+pub const DEFAULT_BASE_URL: &str = "https://api.congress.gov/v3/";
+
+/// The user agent sent with requests unless another is configured.
+pub const DEFAULT_USER_AGENT: &str =
+    concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
 /// Various error types that can occur when using the Cdg struct
 /// and its values.
 #[derive(Debug, Error)]
@@ -23,11 +36,36 @@ pub enum CdgError {
         #[from]
         source: http::uri::InvalidUri,
     },
-    #[error("communication with gitlab: {}", source)]
+    #[cfg(feature = "reqwest")]
+    #[error("communication with congress.gov: {}", redact_str(&.source.to_string()))]
     Communication {
         #[from]
         source: reqwest::Error,
     },
+    #[cfg(feature = "hyper")]
+    #[error("communication with congress.gov: {}", redact_str(&.source.to_string()))]
+    Hyper {
+        #[from]
+        source: hyper_util::client::legacy::Error,
+    },
+    #[cfg(feature = "hyper")]
+    #[error("could not read response body: {}", source)]
+    HyperBody {
+        #[from]
+        source: hyper::Error,
+    },
+    #[cfg(feature = "ureq")]
+    #[error("communication with congress.gov: {}", redact_str(&.source.to_string()))]
+    Ureq {
+        #[from]
+        source: ureq::Error,
+    },
+    #[cfg(feature = "ureq")]
+    #[error("request task failed: {}", source)]
+    Task {
+        #[from]
+        source: tokio::task::JoinError,
+    },
     #[error("HTTP Status Error: {}", status)]
     Status { status: http::StatusCode },
     #[error("HTTP Error: {}", source)]
@@ -45,35 +83,309 @@ pub enum CdgError {
         #[from]
         source: InvalidHeaderValue,
     },
+    #[error("invalid base url `{}`: {}", url, reason)]
+    BaseUrl { url: Url, reason: &'static str },
+    #[error("missing required field `{}`", field)]
+    MissingField { field: &'static str },
+    #[error(
+        "`{}` must be configured on the backend passed to `build_with`",
+        option
+    )]
+    BackendOption { option: &'static str },
+    #[error("could not start runtime: {}", source)]
+    Runtime {
+        #[from]
+        source: std::io::Error,
+    },
+}
+
+impl From<UninitializedFieldError> for CdgError {
+    fn from(error: UninitializedFieldError) -> Self {
+        CdgError::MissingField {
+            field: error.field_name(),
+        }
+    }
 }
 
 /// The primary struct used when consuming Endpoints. Holds
-/// a reusable reqwest::Client, base url, and Auth.
+/// a reusable HTTP client, base url, and Auth.
+///
+/// The HTTP client defaults to the backend selected through cargo features,
+/// see [`backend`](crate::backend).
 #[derive(Debug, Clone)]
-pub struct Cdg {
+pub struct Cdg<B = DefaultBackend> {
     /// The HTTP client to use for API calls.
-    pub client: Client,
+    pub client: B,
     /// The base URL for API calls.
     pub base_url: Url,
     /// The authentication information to use when making API calls.
     pub auth: Auth,
     /// The desired response format. Can be XML or JSON.
     pub format: Format,
+    /// An optional client-side rate limiter shared across clones.
+    pub rate_limiter: Option<RateLimiter>,
+    /// An optional policy for retrying failed requests.
+    pub retry_policy: Option<RetryPolicy>,
+    /// The maximum duration of a query which does not set its own timeout.
+    pub query_timeout: Option<Duration>,
 }
 
 impl Cdg {
+    /// Creates a builder for configuring a Cdg struct.
+    pub fn builder() -> CdgBuilder {
+        CdgBuilder::default()
+    }
+}
+
+impl<B> Cdg<B>
+where
+    B: HttpBackend,
+{
     /// Creates a new Cdg struct with the provided Auth.
-    pub fn new(auth: Auth, client: reqwest::Client, format: Format) -> Result<Cdg, CdgError> {
+    pub fn new(auth: Auth, client: B, format: Format) -> Result<Cdg<B>, CdgError> {
         Ok(Cdg {
             client,
-            base_url: Url::parse("https://api.congress.gov/v3/")?,
+            base_url: Url::parse(DEFAULT_BASE_URL)?,
             auth,
             format,
+            rate_limiter: None,
+            retry_policy: None,
+            query_timeout: None,
+        })
+    }
+
+    /// Limits the rate of requests made by this client and its clones.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// The rate limiter used by this client, if any.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_ref()
+    }
+
+    /// Retries failed requests according to the given policy.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// The retry policy used by this client, if any.
+    pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
+
+    /// Fails queries which do not set their own timeout after the given
+    /// duration, see [`CdgBuilder::query_timeout`].
+    pub fn with_query_timeout(mut self, timeout: Duration) -> Self {
+        self.query_timeout = Some(timeout);
+        self
+    }
+}
+
+/// The options of a [`Cdg`] client, set through a [`CdgBuilder`] returned
+/// by [`Cdg::builder`].
+///
+/// Only the auth is required. The base URL defaults to the congress.gov v3
+/// API and must end with a `/` so that endpoint paths can be joined onto
+/// it, e.g. `http://localhost:8080/v3/`.
+///
+/// The connection options (timeouts, user agent, proxy and default headers)
+/// configure the reqwest client created by [`CdgBuilder::build`]. The hyper
+/// and ureq backends, and a backend given to [`CdgBuilder::build_with`],
+/// must be configured themselves, and setting any of them is an error.
+#[derive(Debug, Clone, Builder)]
+#[builder(
+    name = "CdgBuilder",
+    public,
+    derive(Debug),
+    build_fn(private, name = "options", error = "CdgError")
+)]
+#[builder_struct_attr(
+    doc = "A builder for a [`Cdg`] client, see [`CdgOptions`] for the options.\n"
+)]
+pub struct CdgOptions {
+    /// The authentication information to use when making API calls.
+    auth: Auth,
+    /// The base URL for API calls.
+    #[builder(setter(into, strip_option), default)]
+    base_url: Option<String>,
+    /// The timeout for establishing a connection.
+    #[builder(setter(strip_option), default)]
+    connect_timeout: Option<Duration>,
+    /// The timeout for an entire request, from connecting until the
+    /// response body has been read.
+    #[builder(setter(strip_option), default)]
+    timeout: Option<Duration>,
+    /// The user agent sent with every request.
+    #[builder(setter(into, strip_option), default)]
+    user_agent: Option<String>,
+    /// The response format used when a query does not specify one.
+    #[builder(default)]
+    format: Format,
+    /// A proxy to send all requests through.
+    #[cfg(feature = "reqwest")]
+    #[builder(setter(strip_option), default)]
+    proxy: Option<reqwest::Proxy>,
+    /// Headers sent with every request.
+    #[builder(default)]
+    default_headers: HeaderMap,
+    /// A client-side rate limiter shared across clones of the client.
+    #[builder(setter(strip_option), default)]
+    rate_limiter: Option<RateLimiter>,
+    /// A policy for retrying failed requests.
+    #[builder(setter(strip_option), default)]
+    retry_policy: Option<RetryPolicy>,
+    /// The maximum duration of a query, including retries and waits for the
+    /// rate limiter.
+    ///
+    /// Unlike [`timeout`](Self::timeout), this applies to every backend and
+    /// fails with an [`ApiError::Timeout`] naming the endpoint. Queries may
+    /// override it with [`api::timeout`](crate::api::timeout).
+    #[builder(setter(strip_option), default)]
+    query_timeout: Option<Duration>,
+}
+
+impl CdgOptions {
+    /// The connection options which are set, by name.
+    fn connection_options(&self) -> impl Iterator<Item = &'static str> {
+        #[cfg(feature = "reqwest")]
+        let proxy = self.proxy.is_some();
+        #[cfg(not(feature = "reqwest"))]
+        let proxy = false;
+
+        [
+            ("connect_timeout", self.connect_timeout.is_some()),
+            ("timeout", self.timeout.is_some()),
+            ("user_agent", self.user_agent.is_some()),
+            ("proxy", proxy),
+            ("default_headers", !self.default_headers.is_empty()),
+        ]
+        .into_iter()
+        .filter_map(|(option, set)| set.then_some(option))
+    }
+
+    fn into_cdg<B>(self, backend: B) -> Result<Cdg<B>, CdgError>
+    where
+        B: HttpBackend,
+    {
+        let base_url = Url::parse(self.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL))?;
+        validate_base_url(&base_url)?;
+
+        Ok(Cdg {
+            client: backend,
+            base_url,
+            auth: self.auth,
+            format: self.format,
+            rate_limiter: self.rate_limiter,
+            retry_policy: self.retry_policy,
+            query_timeout: self.query_timeout,
         })
     }
 }
 
-impl crate::api::Client for Cdg {
+impl CdgBuilder {
+    /// Builds the client with a reqwest backend, validating the base URL.
+    #[cfg(feature = "reqwest")]
+    pub fn build(&self) -> Result<Cdg<reqwest::Client>, CdgError> {
+        let options = self.options()?;
+        let mut client = reqwest::Client::builder()
+            .user_agent(options.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT))
+            .default_headers(options.default_headers.clone());
+        if let Some(timeout) = options.connect_timeout {
+            client = client.connect_timeout(timeout);
+        }
+        if let Some(timeout) = options.timeout {
+            client = client.timeout(timeout);
+        }
+        if let Some(proxy) = options.proxy.clone() {
+            client = client.proxy(proxy);
+        }
+
+        options.into_cdg(client.build()?)
+    }
+
+    /// Builds the client with the default hyper or ureq backend, validating
+    /// the base URL.
+    ///
+    /// These backends are created with their defaults, so setting any of the
+    /// connection options fails with [`CdgError::BackendOption`]. Configure
+    /// the backend and pass it to [`build_with`](Self::build_with) instead.
+    #[cfg(not(feature = "reqwest"))]
+    pub fn build(&self) -> Result<Cdg, CdgError> {
+        self.build_with(crate::backend::default_backend())
+    }
+
+    /// Builds the client with the given backend, validating the base URL.
+    ///
+    /// The connection options can't be applied to a backend which has
+    /// already been created, so setting any of them fails with
+    /// [`CdgError::BackendOption`].
+    pub fn build_with<B>(&self, backend: B) -> Result<Cdg<B>, CdgError>
+    where
+        B: HttpBackend,
+    {
+        let options = self.options()?;
+        if let Some(option) = options.connection_options().next() {
+            return Err(CdgError::BackendOption { option });
+        }
+
+        options.into_cdg(backend)
+    }
+}
+
+/// Checks that endpoint paths can be joined onto the base URL without
+/// replacing any part of its path.
+fn validate_base_url(url: &Url) -> Result<(), CdgError> {
+    let reason = if !matches!(url.scheme(), "http" | "https") {
+        "the scheme must be `http` or `https`"
+    } else if url.cannot_be_a_base() {
+        "the url cannot be a base"
+    } else if !url.path().ends_with('/') {
+        "the path must end with `/`"
+    } else if url.query().is_some() || url.fragment().is_some() {
+        "the url must not have a query or fragment"
+    } else {
+        return Ok(());
+    };
+
+    Err(CdgError::BaseUrl {
+        url: url.clone(),
+        reason,
+    })
+}
+
+impl<B> Cdg<B>
+where
+    B: HttpBackend,
+{
+    /// Sends a single request with auth applied, honoring the rate limiter
+    /// if one is set.
+    async fn execute(&self, mut request: Request<Vec<u8>>) -> Result<Response<Bytes>, CdgError> {
+        self.auth.before(&mut request);
+        let (parts, body) = request.into_parts();
+
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        let mut rsp = self
+            .client
+            .send(Request::from_parts(parts.clone(), body))
+            .await?;
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.update_from_headers(rsp.headers());
+        }
+        self.auth.after(&parts, &mut rsp);
+
+        Ok(rsp)
+    }
+}
+
+impl<B> crate::api::Client for Cdg<B>
+where
+    B: HttpBackend,
+{
     type Error = CdgError;
 
     /// Constructs an endpoint URL by combining the base_url
@@ -82,37 +394,284 @@ impl crate::api::Client for Cdg {
         Ok(self.base_url.join(endpoint)?)
     }
 
-    /// Appends the auth token to the URL for the request. The congress.gov
-    /// API expects requests of the form https://api.congress.gov/v3/resource?api_key=[INSERT_KEY].
-    fn set_auth(&self, url: &mut Url) {
-        let mut pairs = url.query_pairs_mut();
-        match &self.auth {
-            Auth::Token(token) => pairs.extend_pairs([("api_key", token)]),
-        };
+    /// Leaves the URL untouched. The auth token is added by the [`Auth`]
+    /// middleware when the request is sent, so URLs seen by layers and
+    /// wrappers around the client never contain the key.
+    fn set_auth(&self, _url: &mut Url) {}
+
+    /// The response format requested for queries which do not override it.
+    fn format(&self) -> Format {
+        self.format
+    }
+
+    /// The query timeout configured for this client.
+    fn timeout(&self) -> Option<Duration> {
+        self.query_timeout
     }
 
     /// Performs a REST API call for the given request and returns the response as Bytes.
+    ///
+    /// When a retry policy is configured, retryable statuses and transport
+    /// errors are retried with backoff. If a request which was retried still
+    /// fails, because every attempt failed or the last failure can't be
+    /// retried, the final failure is returned wrapped in [`ApiError::Retry`].
     async fn rest(
         &self,
         request: http::request::Builder,
         body: Vec<u8>,
     ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
-        let call = || async {
-            let http_request = request.body(body)?;
-            let request: Request = http_request.try_into()?;
-            let rsp = self.client.execute(request).await?;
-
-            let mut http_rsp = Response::builder()
-                .status(rsp.status())
-                .version(rsp.version());
-            let headers = http_rsp.headers_mut().unwrap();
-            headers.insert(http::header::ACCEPT, self.format.as_header()?);
-            for (key, value) in rsp.headers() {
-                headers.insert(key, value.clone());
+        let request = request.body(body).map_err(|e| ApiError::Client {
+            source: CdgError::from(e),
+        })?;
+
+        let Some(retry_policy) = &self.retry_policy else {
+            return self
+                .execute(request)
+                .await
+                .map_err(|e| ApiError::Client { source: e });
+        };
+
+        let mut attempt = 1;
+        loop {
+            let mut result = self.execute(clone_request(&request)).await;
+            if attempt > 1
+                && let Ok(rsp) = &mut result
+            {
+                rsp.extensions_mut().insert(Attempts(attempt));
+            }
+
+            let retryable = match &result {
+                Ok(rsp) => {
+                    !rsp.status().is_success() && retry_policy.is_retryable_status(rsp.status())
+                }
+                Err(e) => retry_policy.retry_transport_errors() && self.client.is_transient(e),
+            };
+
+            // Unsuccessful responses are returned as they are and decoded by the query, which
+            // reports the attempts from the extension.
+            if !retryable || attempt >= retry_policy.max_attempts() {
+                return result.map_err(|e| {
+                    let source = ApiError::Client { source: e };
+                    if attempt > 1 {
+                        ApiError::Retry {
+                            attempts: attempt,
+                            source: Box::new(source),
+                        }
+                    } else {
+                        source
+                    }
+                });
+            }
+
+            let retry_headers = result.as_ref().ok().map(Response::headers);
+            let delay = retry_policy.delay(attempt, retry_headers);
+            #[cfg(feature = "tracing")]
+            {
+                match &result {
+                    Ok(rsp) => tracing::warn!(
+                        attempt,
+                        status = rsp.status().as_u16(),
+                        delay_ms = delay.as_millis() as u64,
+                        "retrying request",
+                    ),
+                    Err(err) => tracing::warn!(
+                        attempt,
+                        error = %err,
+                        delay_ms = delay.as_millis() as u64,
+                        "retrying request",
+                    ),
+                }
+                tracing::Span::current().record("attempts", attempt + 1);
             }
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::Client as _;
+
+    use super::*;
+
+    #[test]
+    fn builder_defaults() {
+        let cdg = Cdg::builder()
+            .auth(Auth::Token("KEY".into()))
+            .build()
+            .unwrap();
+
+        assert_eq!(cdg.base_url.as_str(), DEFAULT_BASE_URL);
+        assert_eq!(cdg.format, Format::Json);
+    }
+
+    #[test]
+    fn builder_auth_is_required() {
+        let err = Cdg::builder().build().unwrap_err();
+
+        assert!(matches!(err, CdgError::MissingField { field: "auth" }));
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn builder_base_url() {
+        let cdg = Cdg::builder()
+            .auth(Auth::Token("KEY".into()))
+            .base_url("http://localhost:8080/v3/")
+            .timeout(Duration::from_secs(5))
+            .format(Format::Xml)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            cdg.rest_endpoint("bill/118").unwrap().as_str(),
+            "http://localhost:8080/v3/bill/118"
+        );
+        assert_eq!(cdg.format, Format::Xml);
+    }
 
-            Ok(http_rsp.body(rsp.bytes().await?)?)
+    #[test]
+    fn builder_base_url_requires_trailing_slash() {
+        let err = Cdg::builder()
+            .auth(Auth::Token("KEY".into()))
+            .base_url("http://localhost:8080/v3")
+            .build()
+            .unwrap_err();
+
+        assert!(matches!(err, CdgError::BaseUrl { .. }));
+    }
+
+    #[test]
+    fn build_with_rejects_connection_options() {
+        let err = Cdg::builder()
+            .auth(Auth::Token("KEY".into()))
+            .timeout(Duration::from_secs(5))
+            .build_with(KeyBackend::default())
+            .unwrap_err();
+
+        assert!(matches!(err, CdgError::BackendOption { option: "timeout" }));
+    }
+
+    /// Rejects requests made with the key `A` as rate limited.
+    #[derive(Debug, Default)]
+    struct KeyBackend {
+        keys: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl HttpBackend for KeyBackend {
+        async fn send(&self, request: Request<Vec<u8>>) -> Result<Response<Bytes>, CdgError> {
+            let key = request.headers()[crate::API_KEY_HEADER].to_str().unwrap();
+            self.keys.lock().unwrap().push(key.into());
+            let status = if key == "A" {
+                http::StatusCode::TOO_MANY_REQUESTS
+            } else {
+                http::StatusCode::OK
+            };
+            Ok(Response::builder()
+                .status(status)
+                .body(Bytes::from_static(b"{}"))
+                .unwrap())
+        }
+
+        fn is_transient(&self, _error: &CdgError) -> bool {
+            false
+        }
+    }
+
+    #[tokio::test]
+    async fn retry_rotates_pooled_key() {
+        let pool = crate::KeyPool::new(["A", "B"]).unwrap();
+        let retry_policy = RetryPolicy::builder()
+            .initial_backoff(Duration::ZERO)
+            .jitter(false)
+            .build()
+            .unwrap();
+        let cdg = Cdg::builder()
+            .auth(Auth::Pool(pool.clone()))
+            .retry_policy(retry_policy)
+            .build_with(KeyBackend::default())
+            .unwrap();
+
+        let rsp = cdg
+            .rest(http::Request::get(DEFAULT_BASE_URL), Vec::new())
+            .await
+            .unwrap();
+
+        assert_eq!(rsp.status(), http::StatusCode::OK);
+        assert_eq!(rsp.extensions().get(), Some(&crate::KeyId(1)));
+        assert_eq!(*cdg.client.keys.lock().unwrap(), ["A", "B"]);
+        assert_eq!(pool.usage()[0].rate_limited, 1);
+    }
+
+    /// Responds with the given statuses in turn.
+    struct StatusBackend {
+        statuses: std::sync::Mutex<Vec<http::StatusCode>>,
+    }
+
+    impl HttpBackend for StatusBackend {
+        async fn send(&self, _request: Request<Vec<u8>>) -> Result<Response<Bytes>, CdgError> {
+            let status = self.statuses.lock().unwrap().remove(0);
+            Ok(Response::builder()
+                .status(status)
+                .body(Bytes::from_static(b"{}"))
+                .unwrap())
+        }
+
+        fn is_transient(&self, _error: &CdgError) -> bool {
+            false
+        }
+    }
+
+    fn status_cdg(statuses: Vec<http::StatusCode>) -> Cdg<StatusBackend> {
+        let retry_policy = RetryPolicy::builder()
+            .initial_backoff(Duration::ZERO)
+            .jitter(false)
+            .build()
+            .unwrap();
+        Cdg::builder()
+            .auth(Auth::Token("KEY".into()))
+            .retry_policy(retry_policy)
+            .build_with(StatusBackend {
+                statuses: std::sync::Mutex::new(statuses),
+            })
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn retry_reports_attempts_of_final_error() {
+        let cdg = status_cdg(vec![
+            http::StatusCode::SERVICE_UNAVAILABLE,
+            http::StatusCode::NOT_FOUND,
+        ]);
+        let endpoint = crate::api::congress::CurrentCongress::builder()
+            .build()
+            .unwrap();
+
+        let err = crate::api::Query::<serde_json::Value, _>::query(&endpoint, &cdg)
+            .await
+            .unwrap_err();
+
+        let ApiError::Retry { attempts, source } = err else {
+            panic!("unexpected error: {err:?}");
         };
-        call().await.map_err(|e| ApiError::Client { source: e })
+        assert_eq!(attempts, 2);
+        assert!(matches!(*source, ApiError::NotFound { .. }), "{source:?}");
+    }
+
+    #[tokio::test]
+    async fn raw_query_returns_final_response() {
+        let cdg = status_cdg(vec![http::StatusCode::SERVICE_UNAVAILABLE; 3]);
+        let endpoint = crate::api::congress::CurrentCongress::builder()
+            .build()
+            .unwrap();
+
+        let rsp = crate::api::RawQuery::raw_query(&endpoint, &cdg)
+            .await
+            .unwrap();
+
+        assert_eq!(rsp.status(), http::StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(rsp.body().as_ref(), b"{}");
+        assert_eq!(rsp.extensions().get(), Some(&Attempts(3)));
     }
 }
@@ -3,18 +3,79 @@
 //! # High-level features
 //!
 //! - REST API bindings are divided between Endpoint, Client, and Query traits
-//! - Auth and state are managed by the Cdg struct
+//! - Auth and state are managed by the Cdg struct, configurable via `Cdg::builder()`
+//! - The API key is sent in the `X-Api-Key` header by default and redacted from errors and
+//!   `Debug` output; `Auth::QueryParam` sends it as the `api_key` parameter instead
+//! - Several keys can be shared through an `Auth::Pool`, which rotates keys as they run out
+//!   of quota
 //! - Optional Endpoint parameters are added to a query via a Builder API
 //! - Responses are returned as a serde_json::Value
+//! - XML responses can be decoded by enabling the `xml` feature and using Format::Xml
+//! - Requests can be rate limited client-side with a RateLimiter shared across Cdg clones
+//! - Transient failures can be retried with exponential backoff via a RetryPolicy
+//! - Requests and responses can be intercepted by stacking `Middleware` layers on any client
+//! - Responses can be cached in memory or on disk with per-resource TTLs via `Cached`
+//! - Any query can return an `api::Response<T>` or `api::Page<T>` to keep the request URL,
+//!   rate-limit headers and pagination alongside the decoded body
+//! - `url` fields linking to related resources can be deserialized as an `api::Link` and
+//!   queried with the same client
+//! - API URLs, e.g. from logs, can be parsed back into endpoints with `api::Route`
+//! - Links to the public congress.gov pages of bills, members and other resources can be built
+//!   and parsed with `web::WebPage`
+//! - Paginated endpoints can be streamed page by page or item by item via `api::paged`
+//! - Many endpoints can be queried concurrently with per-item results via `api::batch`
+//! - Queries can be given a deadline per client or per query via `api::timeout`, and paged or
+//!   batch queries can be cancelled with a `CancellationToken`
+//! - Queries can be instrumented with `tracing` spans by enabling the `tracing` feature
+//! - Code generic over `api::Client` can be unit tested with the `MockClient` from the
+//!   `test-util` feature
+//! - Clients can be tested end to end against the local `TestServer` from the `test-server`
+//!   feature, which serves fixtures and injects faults
 //!
-//! This crate only provides an async implementation.
+//! This crate provides an async implementation. A synchronous client, `BlockingCdg`, is
+//! available with the `blocking` feature.
 
 pub mod api;
 mod auth;
+#[cfg(any(feature = "reqwest", feature = "hyper", feature = "ureq"))]
+pub mod backend;
+#[cfg(all(
+    feature = "blocking",
+    any(feature = "reqwest", feature = "hyper", feature = "ureq")
+))]
+mod blocking;
+mod cache;
+#[cfg(any(feature = "reqwest", feature = "hyper", feature = "ureq"))]
 mod cdg;
+mod middleware;
+mod rate_limit;
+mod retry;
+#[cfg(feature = "test-server")]
+pub mod test_server;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+pub mod web;
 
 #[cfg(test)]
 mod test;
 
-pub use crate::auth::Auth;
-pub use crate::cdg::{Cdg, CdgError};
+pub use crate::auth::{API_KEY_HEADER, Auth, KeyId, KeyPool, KeyPoolError, KeyUsage};
+#[cfg(all(
+    feature = "blocking",
+    any(feature = "reqwest", feature = "hyper", feature = "ureq")
+))]
+pub use crate::blocking::BlockingCdg;
+pub use crate::cache::{
+    CacheControl, CacheMode, CachePolicy, CachePolicyBuilder, CachePolicyBuilderError, CacheStore,
+    Cached, CachedResponse, DiskCache, MemoryCache,
+};
+#[cfg(any(feature = "reqwest", feature = "hyper", feature = "ureq"))]
+pub use crate::cdg::{Cdg, CdgBuilder, CdgError, CdgOptions, DEFAULT_BASE_URL, DEFAULT_USER_AGENT};
+pub use crate::middleware::{Flow, Layered, Middleware};
+pub use crate::rate_limit::{
+    DEFAULT_BURST, DEFAULT_HOURLY_QUOTA, RATE_LIMIT_LIMIT_HEADER, RATE_LIMIT_REMAINING_HEADER,
+    RateLimiter,
+};
+pub use crate::retry::{
+    Attempts, RetryPolicy, RetryPolicyBuilder, RetryPolicyBuilderError, retry_after,
+};
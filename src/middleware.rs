@@ -0,0 +1,314 @@
+use std::{sync::Arc, time::Duration};
+
+use bytes::Bytes;
+use http::{Request, Response, request::Parts};
+use url::Url;
+
+use crate::api::{ApiError, Client, common::Format};
+
+/// What to do with a request after a [`Middleware`] has seen it.
+#[derive(Debug)]
+pub enum Flow {
+    /// Pass the request on to the next layer.
+    Continue,
+    /// Skip the remaining layers and the client, answering the request
+    /// with the given response.
+    Respond(Response<Bytes>),
+}
+
+/// Hooks run around every request made through a [`Layered`] client.
+///
+/// `before` may modify the request, e.g. to add headers or rewrite the URL
+/// for a proxy, or answer it directly. `after` may inspect or modify the
+/// response of a request which was passed on.
+pub trait Middleware: Send + Sync {
+    /// Called before the request is sent.
+    fn before(&self, _request: &mut Request<Vec<u8>>) -> Flow {
+        Flow::Continue
+    }
+
+    /// Called with the response to a request passed on by `before`.
+    fn after(&self, _request: &Parts, _response: &mut Response<Bytes>) {}
+}
+
+impl<M> Middleware for Arc<M>
+where
+    M: Middleware + ?Sized,
+{
+    fn before(&self, request: &mut Request<Vec<u8>>) -> Flow {
+        (**self).before(request)
+    }
+
+    fn after(&self, request: &Parts, response: &mut Response<Bytes>) {
+        (**self).after(request, response)
+    }
+}
+
+impl<M> Middleware for Box<M>
+where
+    M: Middleware + ?Sized,
+{
+    fn before(&self, request: &mut Request<Vec<u8>>) -> Flow {
+        (**self).before(request)
+    }
+
+    fn after(&self, request: &Parts, response: &mut Response<Bytes>) {
+        (**self).after(request, response)
+    }
+}
+
+/// A [`Client`] running a [`Middleware`] around every request of another
+/// client.
+///
+/// Layers stack by wrapping: the outermost layer's `before` hook runs
+/// first and its `after` hook runs last.
+///
+/// ```rust,no_run
+/// # use congressdotgov_rs::{Auth, Cdg, Flow, Layered, Middleware};
+/// # use http::{HeaderValue, Request};
+/// struct Tenant;
+///
+/// impl Middleware for Tenant {
+///     fn before(&self, request: &mut Request<Vec<u8>>) -> Flow {
+///         request
+///             .headers_mut()
+///             .insert("x-tenant", HeaderValue::from_static("research"));
+///         Flow::Continue
+///     }
+/// }
+///
+/// let cdg = Cdg::builder().auth(Auth::Token("API_KEY".into())).build().unwrap();
+/// let client = Layered::new(cdg, Tenant);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Layered<C, M> {
+    client: C,
+    middleware: M,
+}
+
+impl<C, M> Layered<C, M> {
+    /// Runs the middleware around the requests of the client.
+    pub fn new(client: C, middleware: M) -> Self {
+        Layered { client, middleware }
+    }
+
+    /// Adds another layer on top of this one.
+    pub fn layer<N>(self, middleware: N) -> Layered<Self, N> {
+        Layered::new(self, middleware)
+    }
+
+    /// The wrapped client.
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    /// The middleware of this layer.
+    pub fn middleware(&self) -> &M {
+        &self.middleware
+    }
+}
+
+impl<C, M> Client for Layered<C, M>
+where
+    C: Client + Sync,
+    M: Middleware,
+{
+    type Error = C::Error;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.client.rest_endpoint(endpoint)
+    }
+
+    fn set_auth(&self, url: &mut Url) {
+        self.client.set_auth(url)
+    }
+
+    fn format(&self) -> Format {
+        self.client.format()
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.client.timeout()
+    }
+
+    async fn rest(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let mut request = request.body(body)?;
+        if let Flow::Respond(rsp) = self.middleware.before(&mut request) {
+            return Ok(rsp);
+        }
+
+        let (parts, body) = request.into_parts();
+        let mut builder = Request::builder()
+            .method(parts.method.clone())
+            .uri(parts.uri.clone())
+            .version(parts.version);
+        if let Some(headers) = builder.headers_mut() {
+            *headers = parts.headers.clone();
+        }
+        if let Some(extensions) = builder.extensions_mut() {
+            *extensions = parts.extensions.clone();
+        }
+
+        let mut rsp = self.client.rest(builder, body).await?;
+        self.middleware.after(&parts, &mut rsp);
+        Ok(rsp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use http::{HeaderValue, StatusCode};
+    use serde_json::json;
+
+    use crate::{
+        api::{Query, congress},
+        test_util::{MockClient, MockResponse},
+    };
+
+    use super::*;
+
+    fn client() -> MockClient {
+        let client = MockClient::new();
+        client.expect(
+            MockResponse::builder()
+                .endpoint("congress/current")
+                .body(json!({ "congress": {} }))
+                .build()
+                .unwrap(),
+        );
+        client
+    }
+
+    async fn query<C: Client>(client: &C) -> Result<serde_json::Value, ApiError<C::Error>> {
+        let endpoint = congress::CurrentCongress::builder().build().unwrap();
+        endpoint.query(client).await
+    }
+
+    struct Log {
+        name: &'static str,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Middleware for Log {
+        fn before(&self, request: &mut Request<Vec<u8>>) -> Flow {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("before {}", self.name));
+            request
+                .headers_mut()
+                .append("x-layer", HeaderValue::from_static(self.name));
+            Flow::Continue
+        }
+
+        fn after(&self, request: &Parts, _response: &mut Response<Bytes>) {
+            let layers: Vec<_> = request.headers.get_all("x-layer").iter().collect();
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("after {} {:?}", self.name, layers));
+        }
+    }
+
+    #[tokio::test]
+    async fn layers_run_in_order() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let client = Layered::new(
+            client(),
+            Log {
+                name: "inner",
+                log: log.clone(),
+            },
+        )
+        .layer(Log {
+            name: "outer",
+            log: log.clone(),
+        });
+
+        query(&client).await.unwrap();
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "before outer",
+                "before inner",
+                r#"after inner ["outer", "inner"]"#,
+                r#"after outer ["outer"]"#,
+            ]
+        );
+    }
+
+    struct Offline;
+
+    impl Middleware for Offline {
+        fn before(&self, _request: &mut Request<Vec<u8>>) -> Flow {
+            Flow::Respond(
+                Response::builder()
+                    .status(StatusCode::SERVICE_UNAVAILABLE)
+                    .body(Bytes::new())
+                    .unwrap(),
+            )
+        }
+    }
+
+    #[tokio::test]
+    async fn short_circuit() {
+        let mock = client();
+        let client = Layered::new(mock.clone(), Offline);
+
+        let err = query(&client).await.unwrap_err();
+
+        assert!(matches!(err, ApiError::Server { .. }));
+        assert!(mock.calls().is_empty());
+    }
+
+    struct Proxy;
+
+    impl Middleware for Proxy {
+        fn before(&self, request: &mut Request<Vec<u8>>) -> Flow {
+            let uri = request.uri().to_string().replace("/current", "/118");
+            *request.uri_mut() = uri.parse().unwrap();
+            Flow::Continue
+        }
+
+        fn after(&self, _request: &Parts, response: &mut Response<Bytes>) {
+            *response.body_mut() = Bytes::from_static(br#"{"rewritten":true}"#);
+        }
+    }
+
+    #[tokio::test]
+    async fn rewrites_requests_and_responses() {
+        let mock = MockClient::new();
+        mock.expect(
+            MockResponse::builder()
+                .endpoint("congress/118")
+                .build()
+                .unwrap(),
+        );
+        let client = Layered::new(mock.clone(), Proxy);
+
+        assert_eq!(query(&client).await.unwrap(), json!({ "rewritten": true }));
+        assert_eq!(mock.calls()[0].endpoint, "congress/118");
+    }
+
+    #[tokio::test]
+    async fn auth_layer() {
+        let mock = MockClient::new();
+        mock.expect(
+            MockResponse::builder()
+                .endpoint("congress/current")
+                .add_query_param("api_key", "KEY")
+                .build()
+                .unwrap(),
+        );
+        let client = Layered::new(mock.clone(), crate::Auth::QueryParam("KEY".into()));
+
+        query(&client).await.unwrap();
+    }
+}
@@ -0,0 +1,239 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use http::HeaderMap;
+
+/// The number of requests per hour allowed for a congress.gov API key.
+pub const DEFAULT_HOURLY_QUOTA: u32 = 5_000;
+
+/// The number of requests which may be made back to back before the
+/// limiter starts spacing them out.
+pub const DEFAULT_BURST: u32 = 100;
+
+/// The header carrying the hourly quota for the API key.
+pub const RATE_LIMIT_LIMIT_HEADER: &str = "x-ratelimit-limit";
+
+/// The header carrying the number of requests left in the current hour.
+pub const RATE_LIMIT_REMAINING_HEADER: &str = "x-ratelimit-remaining";
+
+const HOUR: Duration = Duration::from_secs(60 * 60);
+
+/// A client-side token bucket limiting the rate of requests made to the
+/// congress.gov API.
+///
+/// The bucket holds up to `burst` tokens and refills at `quota` tokens per
+/// hour. Every request consumes a single token. The limiter also adapts to
+/// the `X-RateLimit-Limit` and `X-RateLimit-Remaining` headers returned by
+/// the API so that requests made with the same key from elsewhere are
+/// accounted for.
+///
+/// Cloning a RateLimiter shares the underlying bucket, so clones of a
+/// [`Cdg`](crate::Cdg) draw from the same budget.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    quota: u32,
+    burst: u32,
+    tokens: f64,
+    last_refill: Instant,
+    server_limit: Option<u32>,
+    server_remaining: Option<u32>,
+}
+
+impl Bucket {
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        let rate = f64::from(self.quota) / HOUR.as_secs_f64();
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * rate).min(f64::from(self.burst));
+        self.last_refill = now;
+    }
+
+    /// The time until a single token is available.
+    fn wait_time(&self) -> Duration {
+        let missing = 1.0 - self.tokens;
+        if missing <= 0.0 {
+            return Duration::ZERO;
+        }
+        let rate = f64::from(self.quota) / HOUR.as_secs_f64();
+        Duration::from_secs_f64(missing / rate)
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(DEFAULT_HOURLY_QUOTA, DEFAULT_BURST)
+    }
+}
+
+impl RateLimiter {
+    /// Creates a new RateLimiter allowing `quota` requests per hour with
+    /// up to `burst` requests made back to back. The bucket starts full.
+    ///
+    /// Both are at least 1, as the bucket would otherwise never refill.
+    pub fn new(quota: u32, burst: u32) -> Self {
+        let burst = burst.max(1);
+        RateLimiter {
+            bucket: Arc::new(Mutex::new(Bucket {
+                quota: quota.max(1),
+                burst,
+                tokens: f64::from(burst),
+                last_refill: Instant::now(),
+                server_limit: None,
+                server_remaining: None,
+            })),
+        }
+    }
+
+    /// The number of requests allowed per hour.
+    pub fn quota(&self) -> u32 {
+        self.bucket.lock().unwrap().quota
+    }
+
+    /// The maximum number of requests which may be made back to back.
+    pub fn burst(&self) -> u32 {
+        self.bucket.lock().unwrap().burst
+    }
+
+    /// The number of requests which may be made right now without waiting.
+    pub fn remaining(&self) -> u32 {
+        let mut bucket = self.bucket.lock().unwrap();
+        bucket.refill(Instant::now());
+        bucket.tokens.floor() as u32
+    }
+
+    /// The hourly quota last reported by the API, if any.
+    pub fn server_limit(&self) -> Option<u32> {
+        self.bucket.lock().unwrap().server_limit
+    }
+
+    /// The remaining hourly budget last reported by the API, if any.
+    pub fn server_remaining(&self) -> Option<u32> {
+        self.bucket.lock().unwrap().server_remaining
+    }
+
+    /// Consumes a token if one is available without waiting.
+    pub fn try_acquire(&self) -> bool {
+        let mut bucket = self.bucket.lock().unwrap();
+        bucket.refill(Instant::now());
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Waits until a token is available and consumes it.
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().unwrap();
+                bucket.refill(Instant::now());
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+                bucket.wait_time()
+            };
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                wait_ms = wait.as_millis() as u64,
+                "waiting for rate limiter"
+            );
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Adapts the limiter to the rate limit headers of an API response.
+    ///
+    /// `X-RateLimit-Limit` replaces the configured hourly quota and
+    /// `X-RateLimit-Remaining` caps the tokens currently available so the
+    /// limiter never allows more requests than the API will accept. A
+    /// reported limit of 0 is treated as 1, so that requests are held back
+    /// rather than never refilled.
+    pub fn update_from_headers(&self, headers: &HeaderMap) {
+        let parse = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u32>().ok())
+        };
+        let limit = parse(RATE_LIMIT_LIMIT_HEADER);
+        let remaining = parse(RATE_LIMIT_REMAINING_HEADER);
+
+        let mut bucket = self.bucket.lock().unwrap();
+        bucket.refill(Instant::now());
+        if let Some(limit) = limit {
+            bucket.quota = limit.max(1);
+            bucket.server_limit = Some(limit);
+        }
+        if let Some(remaining) = remaining {
+            bucket.tokens = bucket.tokens.min(f64::from(remaining));
+            bucket.server_remaining = Some(remaining);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn burst_is_exhausted() {
+        let limiter = RateLimiter::new(3_600, 2);
+
+        assert!(limiter.try_acquire());
+        assert!(limiter.try_acquire());
+        assert!(!limiter.try_acquire());
+        assert_eq!(limiter.remaining(), 0);
+    }
+
+    #[test]
+    fn clones_share_budget() {
+        let limiter = RateLimiter::new(3_600, 1);
+        let clone = limiter.clone();
+
+        assert!(clone.try_acquire());
+        assert!(!limiter.try_acquire());
+    }
+
+    #[test]
+    fn adapts_to_headers() {
+        let limiter = RateLimiter::default();
+        let mut headers = HeaderMap::new();
+        headers.insert(RATE_LIMIT_LIMIT_HEADER, HeaderValue::from_static("1000"));
+        headers.insert(RATE_LIMIT_REMAINING_HEADER, HeaderValue::from_static("3"));
+
+        limiter.update_from_headers(&headers);
+
+        assert_eq!(limiter.quota(), 1_000);
+        assert_eq!(limiter.server_limit(), Some(1_000));
+        assert_eq!(limiter.server_remaining(), Some(3));
+        assert_eq!(limiter.remaining(), 3);
+    }
+
+    #[test]
+    fn zero_quota_waits_for_refill() {
+        let limiter = RateLimiter::new(0, 1);
+        assert_eq!(limiter.quota(), 1);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(RATE_LIMIT_LIMIT_HEADER, HeaderValue::from_static("0"));
+        headers.insert(RATE_LIMIT_REMAINING_HEADER, HeaderValue::from_static("0"));
+        limiter.update_from_headers(&headers);
+
+        assert_eq!(limiter.quota(), 1);
+        assert_eq!(limiter.server_limit(), Some(0));
+        assert!(!limiter.try_acquire());
+        let wait = limiter.bucket.lock().unwrap().wait_time();
+        assert!(wait > Duration::from_secs(60 * 59), "{wait:?}");
+    }
+}
@@ -0,0 +1,166 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+use http::{HeaderMap, StatusCode, header::RETRY_AFTER};
+
+/// Controls how failed requests are retried by [`Cdg`](crate::Cdg).
+///
+/// A request is retried when the response status is one of the retryable
+/// statuses or when a transient transport error occurs, such as a refused
+/// connection or a reset while reading the body. The delay between
+/// attempts grows exponentially from `initial_backoff` up to `max_backoff`,
+/// unless the API asks for a longer pause via the `Retry-After` header.
+#[derive(Debug, Clone, Builder)]
+#[builder(default)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first request.
+    max_attempts: u32,
+    /// The delay before the first retry.
+    initial_backoff: Duration,
+    /// The upper bound for the delay between attempts.
+    max_backoff: Duration,
+    /// The factor the delay grows by after each attempt.
+    multiplier: f64,
+    /// Whether to randomize delays to avoid synchronized retries.
+    jitter: bool,
+    /// Whether to wait for the duration requested by a `Retry-After` header.
+    honor_retry_after: bool,
+    /// The response statuses which are retried.
+    #[builder(setter(into))]
+    retry_statuses: Vec<StatusCode>,
+    /// Whether connection and timeout errors are retried.
+    retry_transport_errors: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: true,
+            honor_retry_after: true,
+            retry_statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            retry_transport_errors: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn builder() -> RetryPolicyBuilder {
+        RetryPolicyBuilder::default()
+    }
+
+    /// The maximum number of attempts, including the first request.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts.max(1)
+    }
+
+    /// Whether a response with the given status should be retried.
+    pub fn is_retryable_status(&self, status: StatusCode) -> bool {
+        self.retry_statuses.contains(&status)
+    }
+
+    /// Whether transient transport errors, as classified by the client's
+    /// [`HttpBackend`](crate::backend::HttpBackend), should be retried.
+    pub fn retry_transport_errors(&self) -> bool {
+        self.retry_transport_errors
+    }
+
+    /// The exponential backoff to wait after the given attempt, without
+    /// jitter. Attempts are counted from 1.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let backoff = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+        if backoff.is_finite() && backoff < self.max_backoff.as_secs_f64() {
+            Duration::from_secs_f64(backoff.max(0.0))
+        } else {
+            self.max_backoff
+        }
+    }
+
+    /// The delay to wait after the given attempt, taking jitter and the
+    /// `Retry-After` header of the failed response into account.
+    pub fn delay(&self, attempt: u32, headers: Option<&HeaderMap>) -> Duration {
+        let mut delay = self.backoff(attempt);
+        if self.jitter {
+            // Equal jitter: keep half of the backoff and randomize the rest.
+            delay = delay / 2 + delay.mul_f64(fastrand::f64()) / 2;
+        }
+        match headers.and_then(retry_after) {
+            Some(retry_after) if self.honor_retry_after => delay.max(retry_after),
+            _ => delay,
+        }
+    }
+}
+
+/// The number of attempts made for a request which was retried.
+///
+/// [`Cdg`](crate::Cdg) attaches it to the final response as an extension, so
+/// that it can be read from the response of a
+/// [`RawQuery`](crate::api::RawQuery). Queries which decode an unsuccessful
+/// response report it as [`ApiError::Retry`](crate::api::ApiError::Retry).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Attempts(pub u32);
+
+/// Parses the `Retry-After` header, given either in seconds or as an
+/// HTTP date.
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    (date.with_timezone(&Utc) - Utc::now()).to_std().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use http::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn backoff_is_capped() {
+        let policy = RetryPolicy::builder()
+            .initial_backoff(Duration::from_secs(1))
+            .max_backoff(Duration::from_secs(5))
+            .build()
+            .unwrap();
+
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(policy.backoff(3), Duration::from_secs(4));
+        assert_eq!(policy.backoff(4), Duration::from_secs(5));
+        assert_eq!(policy.backoff(100), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn honors_retry_after() {
+        let policy = RetryPolicy::builder().jitter(false).build().unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("120"));
+
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(120)));
+        assert_eq!(policy.delay(1, Some(&headers)), Duration::from_secs(120));
+    }
+
+    #[test]
+    fn retryable_statuses() {
+        let policy = RetryPolicy::builder()
+            .retry_statuses([StatusCode::SERVICE_UNAVAILABLE])
+            .build()
+            .unwrap();
+
+        assert!(policy.is_retryable_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!policy.is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+    }
+}
@@ -1,3 +1,5 @@
+pub(crate) mod cassette;
+
 macro_rules! assert_missing_field {
     ($err:expr_2021, $type:tt, $field:expr_2021 $(,)?) => {
         let in_err = $err;
@@ -14,3 +16,19 @@ macro_rules! assert_missing_field {
 }
 
 pub(crate) use assert_missing_field;
+
+/// The URL of an endpoint relative to the API root, with its parameters,
+/// e.g. `bill/117?offset=20&limit=10`.
+pub(crate) fn endpoint_url<E>(endpoint: &E) -> String
+where
+    E: crate::api::Endpoint,
+{
+    let base = url::Url::parse("https://api.congress.gov/v3/").unwrap();
+    let mut url = base.join(&endpoint.endpoint()).unwrap();
+    endpoint.parameters().add_to_url(&mut url);
+    url.as_str()
+        .strip_prefix(base.as_str())
+        .unwrap()
+        .trim_end_matches('?')
+        .into()
+}
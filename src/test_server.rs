@@ -0,0 +1,763 @@
+//! An in-process stand-in for the congress.gov API, for integration tests.
+//!
+//! [`TestServer`] listens on a local port and answers requests under `/v3/` from fixtures, so a
+//! real [`Cdg`](crate::Cdg) client can be pointed at it with
+//! [`CdgBuilder::base_url`](crate::CdgBuilder::base_url). Unlike the
+//! [`MockClient`](crate::test_util::MockClient), requests go through the HTTP backend,
+//! middleware, retries and rate limiting of the client. Faults such as rate limiting or server
+//! errors can be injected to test how they are handled.
+//!
+//! ```rust,no_run
+//! # use congressdotgov_rs::api::{ApiError, Query, bill};
+//! # use congressdotgov_rs::test_server::{Fault, TestServer};
+//! # use congressdotgov_rs::{Auth, Cdg};
+//! # use serde_json::json;
+//! # tokio_test::block_on(async {
+//! let server = TestServer::builder()
+//!     .json("bill/117", json!({ "bills": [] }))
+//!     .start()
+//!     .await
+//!     .unwrap();
+//! let client = Cdg::builder()
+//!     .auth(Auth::Token("API_KEY".into()))
+//!     .base_url(server.base_url())
+//!     .build()
+//!     .unwrap();
+//! let endpoint = bill::Congress::builder().congress(117_u16).build().unwrap();
+//!
+//! let bills: serde_json::Value = endpoint.query(&client).await.unwrap();
+//! assert_eq!(bills, json!({ "bills": [] }));
+//!
+//! server.inject(Fault::ServerError);
+//! let err = Query::<serde_json::Value, _>::query(&endpoint, &client).await.unwrap_err();
+//! assert!(matches!(err, ApiError::Server { .. }));
+//! # })
+//! ```
+//!
+//! Fixtures use the format of the recorded fixtures of this crate, a JSON object with a list of
+//! `interactions`, each with the `method` and `url` of a `request` and the `status`, `headers`
+//! and `body` of its `response`. Requests are matched by method, path and query parameters in
+//! any order, ignoring `format` and `api_key`. Links to `https://api.congress.gov/v3/` in
+//! response bodies are rewritten to the server, so that URLs read from a response point at it.
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    convert::Infallible,
+    fs, io,
+    net::{Ipv4Addr, SocketAddr},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bytes::Bytes;
+use http::{HeaderValue, Method, Request, Response, StatusCode, header};
+use http_body_util::Full;
+use hyper::{body::Incoming, server::conn::http1, service::service_fn};
+use hyper_util::rt::TokioIo;
+use serde::Deserialize;
+use thiserror::Error;
+use tokio::{net::TcpListener, task::JoinHandle};
+use url::Url;
+
+use crate::{
+    auth::API_KEY_HEADER,
+    rate_limit::{RATE_LIMIT_LIMIT_HEADER, RATE_LIMIT_REMAINING_HEADER},
+};
+
+/// The root of the live API, rewritten to the server in response bodies.
+const API_BASE_URL: &str = "https://api.congress.gov/v3/";
+
+/// Errors which may occur when starting a [`TestServer`].
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum TestServerError {
+    #[error("failed to start the test server: {}", source)]
+    Io {
+        #[from]
+        source: io::Error,
+    },
+    #[error("invalid fixture {}: {}", path.display(), source)]
+    Fixture {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    #[error("invalid url {} in fixture {}", url, path.display())]
+    FixtureUrl { path: PathBuf, url: String },
+}
+
+/// A failure injected into the response to a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Fault {
+    /// Respond with `429 Too Many Requests` and a `Retry-After` header.
+    RateLimited { retry_after: Duration },
+    /// Respond with `500 Internal Server Error`.
+    ServerError,
+    /// Wait before responding as usual.
+    Slow(Duration),
+    /// Respond with `200 OK` and a truncated JSON body.
+    MalformedJson,
+    /// Respond with `403 Forbidden`, as for an invalid API key.
+    InvalidApiKey,
+}
+
+/// A request received by a [`TestServer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestRequest {
+    /// The method of the request.
+    pub method: Method,
+    /// The endpoint path, relative to the API root.
+    pub endpoint: String,
+    /// The query parameters, in request order, without `format` and `api_key`.
+    pub query: Vec<(String, String)>,
+}
+
+/// A response served for requests to an endpoint.
+#[derive(Debug, Clone)]
+struct Fixture {
+    method: Method,
+    endpoint: String,
+    /// The query parameters, sorted.
+    query: Vec<(String, String)>,
+    status: StatusCode,
+    headers: Vec<(String, String)>,
+    body: Bytes,
+}
+
+#[derive(Deserialize)]
+struct FixtureFile {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Deserialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Deserialize)]
+struct RecordedRequest {
+    method: String,
+    url: String,
+}
+
+#[derive(Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    #[serde(default)]
+    body: Option<serde_json::Value>,
+    #[serde(default)]
+    body_text: Option<String>,
+}
+
+/// A builder for a [`TestServer`].
+#[derive(Debug, Default, Clone)]
+pub struct TestServerBuilder {
+    fixtures: Vec<Fixture>,
+    fixture_dirs: Vec<PathBuf>,
+    api_key: Option<String>,
+    rate_limit: Option<u32>,
+}
+
+impl TestServerBuilder {
+    /// Serve the fixtures in a directory and its subdirectories, e.g. `fixtures/`.
+    pub fn fixtures<P>(&mut self, dir: P) -> &mut Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.fixture_dirs.push(dir.into());
+        self
+    }
+
+    /// Serve a JSON body for `GET` requests to an endpoint, given relative to the API root along
+    /// with its query parameters, e.g. `bill/117?offset=20`.
+    pub fn json<E>(&mut self, endpoint: E, body: serde_json::Value) -> &mut Self
+    where
+        E: AsRef<str>,
+    {
+        let url = Url::parse(API_BASE_URL)
+            .and_then(|base| base.join(endpoint.as_ref()))
+            .expect("endpoints are relative paths");
+        let (endpoint, query) = split_url(&url).expect("endpoints are below the API root");
+
+        self.fixtures.push(Fixture {
+            method: Method::GET,
+            endpoint,
+            query: sorted(query),
+            status: StatusCode::OK,
+            headers: Vec::new(),
+            body: Bytes::from(body.to_string()),
+        });
+        self
+    }
+
+    /// Only accept requests with this API key, in the `X-Api-Key` header or `api_key` parameter.
+    /// By default, any key is accepted.
+    pub fn api_key<K>(&mut self, api_key: K) -> &mut Self
+    where
+        K: Into<String>,
+    {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Answer with `429 Too Many Requests` once this many requests have been served, and report
+    /// the quota in the `X-RateLimit-Limit` and `X-RateLimit-Remaining` headers.
+    pub fn rate_limit(&mut self, limit: u32) -> &mut Self {
+        self.rate_limit = Some(limit);
+        self
+    }
+
+    /// Start the server on a free local port.
+    ///
+    /// The server runs on the current tokio runtime until it is dropped.
+    pub async fn start(&self) -> Result<TestServer, TestServerError> {
+        let mut fixtures = self.fixtures.clone();
+        for dir in &self.fixture_dirs {
+            load_fixtures(dir, &mut fixtures)?;
+        }
+
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let addr = listener.local_addr()?;
+        let base_url = Url::parse(&format!("http://{addr}/v3/")).expect("the address is valid");
+
+        let state = Arc::new(State {
+            base_url,
+            fixtures,
+            api_key: self.api_key.clone(),
+            rate_limit: self.rate_limit,
+            served: Mutex::new(0),
+            faults: Mutex::new(VecDeque::new()),
+            requests: Mutex::new(Vec::new()),
+        });
+
+        let task = tokio::spawn(serve(listener, state.clone()));
+
+        Ok(TestServer { addr, state, task })
+    }
+}
+
+/// A local HTTP server standing in for the congress.gov API.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug)]
+pub struct TestServer {
+    addr: SocketAddr,
+    state: Arc<State>,
+    task: JoinHandle<()>,
+}
+
+impl TestServer {
+    /// Create a builder for the server.
+    pub fn builder() -> TestServerBuilder {
+        TestServerBuilder::default()
+    }
+
+    /// The address the server listens on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// The root of the API on the server, e.g. `http://127.0.0.1:40123/v3/`, to be used as the
+    /// base URL of a client.
+    pub fn base_url(&self) -> String {
+        self.state.base_url.to_string()
+    }
+
+    /// Inject a fault into the response to the next request. Faults injected several times are
+    /// applied to consecutive requests, in order.
+    pub fn inject(&self, fault: Fault) -> &Self {
+        self.state.faults.lock().unwrap().push_back(fault);
+        self
+    }
+
+    /// The requests received so far, in order.
+    pub fn requests(&self) -> Vec<TestRequest> {
+        self.state.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    base_url: Url,
+    fixtures: Vec<Fixture>,
+    api_key: Option<String>,
+    rate_limit: Option<u32>,
+    served: Mutex<u32>,
+    faults: Mutex<VecDeque<Fault>>,
+    requests: Mutex<Vec<TestRequest>>,
+}
+
+impl State {
+    async fn respond(&self, request: Request<Incoming>) -> Response<Full<Bytes>> {
+        let url = self
+            .base_url
+            .join(&request.uri().to_string())
+            .unwrap_or_else(|_| self.base_url.clone());
+        let Some((endpoint, query)) = split_url(&url) else {
+            return error(StatusCode::NOT_FOUND, "the API is served under /v3/");
+        };
+        self.requests.lock().unwrap().push(TestRequest {
+            method: request.method().clone(),
+            endpoint: endpoint.clone(),
+            query: query.clone(),
+        });
+
+        let fault = self.faults.lock().unwrap().pop_front();
+        match fault {
+            Some(Fault::RateLimited { retry_after }) => return rate_limited(retry_after),
+            Some(Fault::ServerError) => {
+                return error(StatusCode::INTERNAL_SERVER_ERROR, "Internal Server Error");
+            }
+            Some(Fault::InvalidApiKey) => return invalid_api_key(),
+            Some(Fault::Slow(delay)) => tokio::time::sleep(delay).await,
+            Some(Fault::MalformedJson) | None => {}
+        }
+
+        if let Some(api_key) = &self.api_key {
+            let header = request
+                .headers()
+                .get(API_KEY_HEADER)
+                .and_then(|value| value.to_str().ok());
+            let param = url
+                .query_pairs()
+                .find(|(key, _)| key == "api_key")
+                .map(|(_, value)| value);
+            if header != Some(api_key.as_str()) && param.as_deref() != Some(api_key.as_str()) {
+                return invalid_api_key();
+            }
+        }
+
+        let remaining = match self.rate_limit {
+            Some(limit) => {
+                let mut served = self.served.lock().unwrap();
+                if *served >= limit {
+                    return rate_limited(Duration::from_secs(3600));
+                }
+                *served += 1;
+                Some((limit, limit - *served))
+            }
+            None => None,
+        };
+
+        let query = sorted(query);
+        let Some(fixture) = self.fixtures.iter().find(|fixture| {
+            fixture.method == request.method()
+                && fixture.endpoint == endpoint
+                && fixture.query == query
+        }) else {
+            return error(
+                StatusCode::NOT_FOUND,
+                &format!("no fixture for {} {}", request.method(), endpoint),
+            );
+        };
+
+        let body = match std::str::from_utf8(&fixture.body) {
+            Ok(body) => Bytes::from(body.replace(API_BASE_URL, self.base_url.as_str())),
+            Err(_) => fixture.body.clone(),
+        };
+        let body = match fault {
+            Some(Fault::MalformedJson) => body.slice(..body.len() / 2),
+            _ => body,
+        };
+
+        let mut response = Response::builder().status(fixture.status);
+        for (name, value) in &fixture.headers {
+            if !name.eq_ignore_ascii_case(header::CONTENT_LENGTH.as_str()) {
+                response = response.header(name, value);
+            }
+        }
+        if !fixture
+            .headers
+            .iter()
+            .any(|(name, _)| name.eq_ignore_ascii_case(header::CONTENT_TYPE.as_str()))
+        {
+            response = response.header(header::CONTENT_TYPE, "application/json");
+        }
+        if let Some((limit, remaining)) = remaining {
+            response = response
+                .header(RATE_LIMIT_LIMIT_HEADER, limit)
+                .header(RATE_LIMIT_REMAINING_HEADER, remaining);
+        }
+
+        response
+            .body(Full::new(body))
+            .unwrap_or_else(|_| error(StatusCode::INTERNAL_SERVER_ERROR, "invalid fixture"))
+    }
+}
+
+async fn serve(listener: TcpListener, state: Arc<State>) {
+    loop {
+        let Ok((stream, _)) = listener.accept().await else {
+            continue;
+        };
+        let state = state.clone();
+        tokio::spawn(async move {
+            let service = service_fn(move |request| {
+                let state = state.clone();
+                async move { Ok::<_, Infallible>(state.respond(request).await) }
+            });
+            // Errors only concern the connection, e.g. a client going away.
+            let _ = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await;
+        });
+    }
+}
+
+/// An error response in the shape used by api.data.gov.
+fn error_with_code(status: StatusCode, code: &str, message: &str) -> Response<Full<Bytes>> {
+    let body = serde_json::json!({ "error": { "code": code, "message": message } });
+    json_response(status, body)
+}
+
+/// An error response in the shape used by congress.gov.
+fn error(status: StatusCode, message: &str) -> Response<Full<Bytes>> {
+    json_response(status, serde_json::json!({ "error": message }))
+}
+
+fn rate_limited(retry_after: Duration) -> Response<Full<Bytes>> {
+    let mut response = error_with_code(
+        StatusCode::TOO_MANY_REQUESTS,
+        "OVER_RATE_LIMIT",
+        "You have exceeded your rate limit.",
+    );
+    response.headers_mut().insert(
+        header::RETRY_AFTER,
+        HeaderValue::from(retry_after.as_secs()),
+    );
+    response
+}
+
+fn invalid_api_key() -> Response<Full<Bytes>> {
+    error_with_code(
+        StatusCode::FORBIDDEN,
+        "API_KEY_INVALID",
+        "An invalid api_key was supplied.",
+    )
+}
+
+fn json_response(status: StatusCode, body: serde_json::Value) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::new(Bytes::from(body.to_string())));
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/json"),
+    );
+    response
+}
+
+/// The endpoint path relative to `/v3/` and the query parameters of a URL, without `format` and
+/// `api_key`.
+fn split_url(url: &Url) -> Option<(String, Vec<(String, String)>)> {
+    let endpoint = url.path().split_once("/v3/")?.1.to_string();
+    let query = url
+        .query_pairs()
+        .filter(|(key, _)| key != "format" && key != "api_key")
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+
+    Some((endpoint, query))
+}
+
+fn sorted(mut query: Vec<(String, String)>) -> Vec<(String, String)> {
+    query.sort();
+    query
+}
+
+fn load_fixtures(dir: &Path, fixtures: &mut Vec<Fixture>) -> Result<(), TestServerError> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            load_fixtures(&path, fixtures)?;
+            continue;
+        }
+        if path.extension().is_none_or(|extension| extension != "json") {
+            continue;
+        }
+
+        let file: FixtureFile =
+            serde_json::from_str(&fs::read_to_string(&path)?).map_err(|source| {
+                TestServerError::Fixture {
+                    path: path.clone(),
+                    source,
+                }
+            })?;
+        for Interaction { request, response } in file.interactions {
+            let invalid = || TestServerError::FixtureUrl {
+                path: path.clone(),
+                url: request.url.clone(),
+            };
+            let url = Url::parse(&request.url).map_err(|_| invalid())?;
+            let (endpoint, query) = split_url(&url).ok_or_else(invalid)?;
+            let body = match (response.body, response.body_text) {
+                (Some(body), _) => Bytes::from(body.to_string()),
+                (None, Some(text)) => Bytes::from(text),
+                (None, None) => Bytes::new(),
+            };
+
+            fixtures.push(Fixture {
+                method: request.method.parse().map_err(|_| invalid())?,
+                endpoint,
+                query: sorted(query),
+                status: StatusCode::from_u16(response.status).map_err(|_| invalid())?,
+                headers: response.headers.into_iter().collect(),
+                body,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, any(feature = "reqwest", feature = "hyper", feature = "ureq")))]
+mod tests {
+    use futures_util::TryStreamExt;
+    use serde_json::json;
+
+    use crate::{
+        Auth, Cdg, RetryPolicy,
+        api::{self, ApiError, Pagination, Query, RawQuery, bill, member},
+        retry_after,
+    };
+
+    use super::*;
+
+    fn client(server: &TestServer, key: &str) -> Cdg {
+        Cdg::builder()
+            .auth(Auth::Token(key.into()))
+            .base_url(server.base_url())
+            .build()
+            .unwrap()
+    }
+
+    fn endpoint() -> bill::Congress {
+        bill::Congress::builder().congress(117_u16).build().unwrap()
+    }
+
+    async fn query(
+        endpoint: &bill::Congress,
+        client: &Cdg,
+    ) -> Result<serde_json::Value, ApiError<crate::CdgError>> {
+        endpoint.query(client).await
+    }
+
+    #[tokio::test]
+    async fn pages_stay_local() {
+        let server = TestServer::builder()
+            .json(
+                "bill/117?limit=1",
+                json!({
+                    "bills": [{ "number": "1" }],
+                    "pagination": {
+                        "count": 2,
+                        "next": "https://api.congress.gov/v3/bill/117?offset=1&limit=1&format=json",
+                    },
+                }),
+            )
+            .json(
+                "bill/117?offset=1&limit=1",
+                json!({ "bills": [{ "number": "2" }], "pagination": { "count": 2 } }),
+            )
+            .start()
+            .await
+            .unwrap();
+        let client = client(&server, "KEY");
+        let endpoint = bill::Congress::builder()
+            .congress(117_u16)
+            .limit(1_u8)
+            .build()
+            .unwrap();
+
+        let bills: Vec<serde_json::Value> = api::paged(endpoint, Pagination::All)
+            .stream(&client)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(bills, [json!({ "number": "1" }), json!({ "number": "2" })]);
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(
+            requests[1].query,
+            [("offset".into(), "1".into()), ("limit".into(), "1".into())]
+        );
+    }
+
+    #[tokio::test]
+    async fn serves_fixture_directories() {
+        let server = TestServer::builder()
+            .fixtures(Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/member/member"))
+            .start()
+            .await
+            .unwrap();
+        let endpoint = member::Member::builder().build().unwrap();
+
+        let rsp: api::Response<serde_json::Value> =
+            endpoint.query(&client(&server, "KEY")).await.unwrap();
+
+        assert_eq!(rsp.body()["members"][0]["bioguideId"], "L000174");
+        assert_eq!(rsp.ratelimit_remaining(), Some(4999));
+    }
+
+    #[tokio::test]
+    async fn injects_faults() {
+        let server = TestServer::builder()
+            .json("bill/117", json!({ "bills": [] }))
+            .start()
+            .await
+            .unwrap();
+        let client = client(&server, "KEY");
+        let endpoint = endpoint();
+
+        server
+            .inject(Fault::RateLimited {
+                retry_after: Duration::from_secs(7),
+            })
+            .inject(Fault::ServerError)
+            .inject(Fault::MalformedJson)
+            .inject(Fault::InvalidApiKey);
+
+        let err = query(&endpoint, &client).await.unwrap_err();
+        let ApiError::RateLimited { response } = &err else {
+            panic!("unexpected error {err:?}");
+        };
+        assert_eq!(response.code.as_deref(), Some("OVER_RATE_LIMIT"));
+        assert!(matches!(
+            query(&endpoint, &client).await,
+            Err(ApiError::Server { .. })
+        ));
+        assert!(matches!(
+            query(&endpoint, &client).await,
+            Err(ApiError::DataType { .. })
+        ));
+        assert!(matches!(
+            query(&endpoint, &client).await,
+            Err(ApiError::InvalidApiKey { .. })
+        ));
+        assert_eq!(
+            query(&endpoint, &client).await.unwrap(),
+            json!({ "bills": [] })
+        );
+    }
+
+    #[tokio::test]
+    async fn truncates_multibyte_bodies() {
+        let body = json!({ "bills": [{ "name": "Rep. Velázquez, Nydia M. [D-NY-7]" }] });
+        let text = body.to_string();
+        assert!(!text.is_char_boundary(text.len() / 2));
+        let server = TestServer::builder()
+            .json("bill/117", body.clone())
+            .start()
+            .await
+            .unwrap();
+        let client = client(&server, "KEY");
+        let endpoint = endpoint();
+
+        server.inject(Fault::MalformedJson);
+
+        assert!(matches!(
+            query(&endpoint, &client).await,
+            Err(ApiError::DataType { .. })
+        ));
+        assert_eq!(query(&endpoint, &client).await.unwrap(), body);
+    }
+
+    #[tokio::test]
+    async fn retry_after_header() {
+        let server = TestServer::builder()
+            .json("bill/117", json!({ "bills": [] }))
+            .start()
+            .await
+            .unwrap();
+        server.inject(Fault::RateLimited {
+            retry_after: Duration::from_secs(7),
+        });
+
+        let rsp = endpoint().raw_query(&client(&server, "KEY")).await.unwrap();
+
+        assert_eq!(rsp.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(retry_after(rsp.headers()), Some(Duration::from_secs(7)));
+    }
+
+    #[tokio::test]
+    async fn retries_server_errors() {
+        let server = TestServer::builder()
+            .json("bill/117", json!({ "bills": [] }))
+            .start()
+            .await
+            .unwrap();
+        server.inject(Fault::ServerError).inject(Fault::ServerError);
+        let client = Cdg::builder()
+            .auth(Auth::Token("KEY".into()))
+            .base_url(server.base_url())
+            .retry_policy(
+                RetryPolicy::builder()
+                    .max_attempts(3_u32)
+                    .initial_backoff(Duration::from_millis(1))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            query(&endpoint(), &client).await.unwrap(),
+            json!({ "bills": [] })
+        );
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn slow_responses_time_out() {
+        let server = TestServer::builder()
+            .json("bill/117", json!({ "bills": [] }))
+            .start()
+            .await
+            .unwrap();
+        server.inject(Fault::Slow(Duration::from_secs(5)));
+        let endpoint = api::timeout(endpoint(), Duration::from_millis(20));
+
+        let err = Query::<serde_json::Value, _>::query(&endpoint, &client(&server, "KEY"))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::Timeout { .. }));
+    }
+
+    #[tokio::test]
+    async fn checks_api_key_and_rate_limit() {
+        let server = TestServer::builder()
+            .json("bill/117", json!({ "bills": [] }))
+            .api_key("KEY")
+            .rate_limit(2)
+            .start()
+            .await
+            .unwrap();
+        let endpoint = endpoint();
+
+        assert!(matches!(
+            query(&endpoint, &client(&server, "WRONG")).await,
+            Err(ApiError::InvalidApiKey { .. })
+        ));
+
+        let client = client(&server, "KEY");
+        let rsp: api::Response<serde_json::Value> = endpoint.query(&client).await.unwrap();
+        assert_eq!(rsp.ratelimit_limit(), Some(2));
+        assert_eq!(rsp.ratelimit_remaining(), Some(1));
+        query(&endpoint, &client).await.unwrap();
+        assert!(matches!(
+            query(&endpoint, &client).await,
+            Err(ApiError::RateLimited { .. })
+        ));
+    }
+}
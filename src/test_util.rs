@@ -0,0 +1,475 @@
+//! Utilities for testing code which queries the congress.gov API.
+//!
+//! [`MockClient`] implements [`Client`] without touching the network, so
+//! code generic over `C: api::Client` can be unit tested against canned
+//! responses:
+//!
+//! ```rust
+//! # use congressdotgov_rs::api::{Query, bill};
+//! # use congressdotgov_rs::test_util::{MockClient, MockResponse};
+//! # use serde_json::json;
+//! # tokio_test::block_on(async {
+//! let client = MockClient::new();
+//! client.expect(
+//!     MockResponse::builder()
+//!         .endpoint("bill/117")
+//!         .add_query_param("limit", "1")
+//!         .body(json!({ "bills": [] }))
+//!         .build()
+//!         .unwrap(),
+//! );
+//!
+//! let endpoint = bill::Congress::builder().congress(117_u8).limit(1_u8).build().unwrap();
+//! let bills: serde_json::Value = endpoint.query(&client).await.unwrap();
+//!
+//! assert_eq!(bills, json!({ "bills": [] }));
+//! assert_eq!(client.calls()[0].endpoint, "bill/117");
+//! # })
+//! ```
+
+use std::{
+    borrow::Cow,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bytes::Bytes;
+use derive_builder::Builder;
+use http::{Method, Response, StatusCode};
+use thiserror::Error;
+use url::Url;
+
+use crate::api::{ApiError, Client};
+
+/// The base URL requests made with a [`MockClient`] are resolved against.
+const MOCK_BASE_URL: &str = "https://api.congress.gov/v3/";
+
+/// Errors returned by a [`MockClient`].
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum MockError {
+    #[error("unexpected request: {} {}", method, url)]
+    Unexpected { method: Method, url: Url },
+    #[error("invalid mock response: {}", source)]
+    Response {
+        #[from]
+        source: http::Error,
+    },
+}
+
+/// A canned response for requests to an endpoint.
+///
+/// A request matches when its method and endpoint path are equal and it
+/// carries exactly the given query parameters, in any order. The `format`
+/// parameter added to every request and the client's own
+/// [`api_key`](MockClient::with_api_key) are ignored.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct MockResponse {
+    /// The method of the request.
+    #[builder(default = "Method::GET")]
+    method: Method,
+    /// The endpoint path, as returned by `Endpoint::endpoint()`.
+    #[builder(setter(into))]
+    endpoint: String,
+    /// The query parameters, as returned by `Endpoint::parameters()`.
+    #[builder(setter(custom), default)]
+    query: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    /// The status of the response.
+    #[builder(default = "StatusCode::OK")]
+    status: StatusCode,
+    /// The JSON body of the response.
+    #[builder(setter(into), default)]
+    body: serde_json::Value,
+    /// The body of the response as bytes, sent instead of the JSON body,
+    /// e.g. for responses which are not JSON.
+    #[builder(setter(into), default)]
+    raw_body: Option<Bytes>,
+    /// Headers of the response, in addition to the JSON content type.
+    #[builder(setter(custom), default)]
+    headers: Vec<(String, String)>,
+    /// The time to wait before responding.
+    #[builder(default)]
+    delay: Duration,
+}
+
+impl MockResponse {
+    /// Create a builder for the response.
+    pub fn builder() -> MockResponseBuilder {
+        MockResponseBuilder::default()
+    }
+
+    fn response(&self) -> Result<Response<Bytes>, http::Error> {
+        let mut rsp = Response::builder()
+            .status(self.status)
+            .header(http::header::CONTENT_TYPE, "application/json");
+        for (name, value) in &self.headers {
+            rsp = rsp.header(name, value);
+        }
+        let body = match &self.raw_body {
+            Some(body) => body.clone(),
+            None => Bytes::from(self.body.to_string()),
+        };
+        rsp.body(body)
+    }
+
+    fn matches(&self, call: &MockCall, api_key: Option<&str>) -> bool {
+        if self.method != call.method || self.endpoint != call.endpoint {
+            return false;
+        }
+
+        let mut expected: Vec<(&str, &str)> = self
+            .query
+            .iter()
+            .map(|(key, value)| (key.as_ref(), value.as_ref()))
+            .collect();
+        let mut actual: Vec<(&str, &str)> = call
+            .query
+            .iter()
+            .filter(|(key, value)| key != "api_key" || api_key != Some(value.as_str()))
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect();
+        expected.sort_unstable();
+        actual.sort_unstable();
+        expected == actual
+    }
+}
+
+impl MockResponseBuilder {
+    /// Add a header to the response.
+    pub fn header<N, V>(&mut self, name: N, value: V) -> &mut Self
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.headers
+            .get_or_insert_with(Vec::new)
+            .push((name.into(), value.into()));
+        self
+    }
+
+    /// Expect a query parameter.
+    pub fn add_query_param<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<Cow<'static, str>>,
+        V: Into<Cow<'static, str>>,
+    {
+        self.query
+            .get_or_insert_with(Vec::new)
+            .push((key.into(), value.into()));
+        self
+    }
+
+    /// Expect multiple query parameters.
+    pub fn add_query_params<I, K, V>(&mut self, params: I) -> &mut Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<Cow<'static, str>>,
+        V: Into<Cow<'static, str>>,
+    {
+        self.query
+            .get_or_insert_with(Vec::new)
+            .extend(params.into_iter().map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+}
+
+/// A request received by a [`MockClient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockCall {
+    /// The method of the request.
+    pub method: Method,
+    /// The endpoint path, relative to the API root.
+    pub endpoint: String,
+    /// The query parameters, in request order, without `format`. The
+    /// `api_key` is included when the client has one.
+    pub query: Vec<(String, String)>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    responses: Vec<MockResponse>,
+    calls: Vec<MockCall>,
+}
+
+/// A client returning canned responses registered with
+/// [`expect`](Self::expect).
+///
+/// Every request is recorded and can be inspected with
+/// [`calls`](Self::calls). Requests without a matching response fail with
+/// [`MockError::Unexpected`]. Cloning a MockClient shares its responses
+/// and recorded calls.
+///
+/// Responses with a [`delay`](MockResponseBuilder::delay) wait on tokio's
+/// clock, so tests of timeouts and concurrency can run with
+/// [`tokio::time::pause`] instead of waiting in real time.
+#[derive(Debug, Clone, Default)]
+pub struct MockClient {
+    state: Arc<Mutex<MockState>>,
+    api_key: Option<String>,
+    timeout: Option<Duration>,
+}
+
+impl MockClient {
+    /// Create a client without any expected requests.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send the given key as the `api_key` query parameter, e.g. to check
+    /// that it is redacted.
+    pub fn with_api_key<K>(mut self, api_key: K) -> Self
+    where
+        K: Into<String>,
+    {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Fail queries which do not set their own timeout after the given
+    /// duration.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Register a response. When several responses match a request, the
+    /// first registered one is returned.
+    pub fn expect(&self, response: MockResponse) -> &Self {
+        self.state.lock().unwrap().responses.push(response);
+        self
+    }
+
+    /// The requests made so far, in order.
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.state.lock().unwrap().calls.clone()
+    }
+
+    fn call(url: &Url, method: Method) -> MockCall {
+        let endpoint = url
+            .as_str()
+            .strip_prefix(MOCK_BASE_URL)
+            .unwrap_or_else(|| url.path())
+            .split(['?', '#'])
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        let query = url
+            .query_pairs()
+            .filter(|(key, _)| key != "format")
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+
+        MockCall {
+            method,
+            endpoint,
+            query,
+        }
+    }
+}
+
+impl Client for MockClient {
+    type Error = MockError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(Url::parse(MOCK_BASE_URL)?.join(endpoint)?)
+    }
+
+    fn set_auth(&self, url: &mut Url) {
+        if let Some(api_key) = &self.api_key {
+            url.query_pairs_mut().append_pair("api_key", api_key);
+        }
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    async fn rest(
+        &self,
+        request: http::request::Builder,
+        _body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let method = request.method_ref().cloned().unwrap_or_default();
+        let url = Url::parse(
+            &request
+                .uri_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+        )?;
+        let call = Self::call(&url, method);
+
+        let response = {
+            let mut state = self.state.lock().unwrap();
+            state.calls.push(call.clone());
+            state
+                .responses
+                .iter()
+                .find(|response| response.matches(&call, self.api_key.as_deref()))
+                .cloned()
+                .ok_or_else(|| ApiError::Client {
+                    source: MockError::Unexpected {
+                        method: call.method.clone(),
+                        url: url.clone(),
+                    },
+                })?
+        };
+
+        if !response.delay.is_zero() {
+            tokio::time::sleep(response.delay).await;
+        }
+        response
+            .response()
+            .map_err(|e| ApiError::Client { source: e.into() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::api::{Pagination, Query, RawQuery, bill, paged};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn matches_endpoint_and_params() {
+        let client = MockClient::new();
+        client.expect(
+            MockResponse::builder()
+                .endpoint("bill/117")
+                .add_query_params([("limit", "5"), ("offset", "10")])
+                .body(json!({ "bills": [{ "number": "1" }] }))
+                .build()
+                .unwrap(),
+        );
+
+        let endpoint = bill::Congress::builder()
+            .congress(117_u8)
+            .offset(10_u32)
+            .limit(5_u8)
+            .build()
+            .unwrap();
+        let res: serde_json::Value = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(res["bills"][0]["number"], "1");
+        assert_eq!(
+            client.calls(),
+            vec![MockCall {
+                method: Method::GET,
+                endpoint: "bill/117".into(),
+                query: vec![("offset".into(), "10".into()), ("limit".into(), "5".into())],
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_status() {
+        let client = MockClient::new();
+        client.expect(
+            MockResponse::builder()
+                .endpoint("bill/117")
+                .status(StatusCode::NOT_FOUND)
+                .body(json!({ "error": "Unknown resource" }))
+                .build()
+                .unwrap(),
+        );
+
+        let endpoint = bill::Congress::builder().congress(117_u8).build().unwrap();
+        let err = Query::<serde_json::Value, _>::query(&endpoint, &client)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::NotFound { .. }));
+    }
+
+    #[tokio::test]
+    async fn unexpected_request_fails() {
+        let client = MockClient::new();
+        client.expect(
+            MockResponse::builder()
+                .endpoint("bill/117")
+                .build()
+                .unwrap(),
+        );
+
+        let endpoint = bill::Congress::builder()
+            .congress(117_u8)
+            .limit(5_u8)
+            .build()
+            .unwrap();
+        let err = Query::<serde_json::Value, _>::query(&endpoint, &client)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ApiError::Client {
+                source: MockError::Unexpected { .. }
+            }
+        ));
+        assert_eq!(client.calls().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn follows_pages() {
+        let client = MockClient::new();
+        client
+            .expect(
+                MockResponse::builder()
+                    .endpoint("bill/117")
+                    .body(json!({
+                        "bills": [1],
+                        "pagination": {
+                            "count": 2,
+                            "next": "https://api.congress.gov/v3/bill/117?offset=1&limit=1",
+                        },
+                    }))
+                    .build()
+                    .unwrap(),
+            )
+            .expect(
+                MockResponse::builder()
+                    .endpoint("bill/117")
+                    .add_query_params([("offset", "1"), ("limit", "1")])
+                    .body(json!({ "bills": [2], "pagination": { "count": 2 } }))
+                    .build()
+                    .unwrap(),
+            );
+
+        let endpoint = bill::Congress::builder().congress(117_u8).build().unwrap();
+        let bills: Vec<u32> = paged(endpoint, Pagination::All)
+            .query(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(bills, vec![1, 2]);
+        assert_eq!(client.calls().len(), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn delays_response() {
+        let client = MockClient::new().with_api_key("KEY");
+        client.expect(
+            MockResponse::builder()
+                .endpoint("bill/117")
+                .header("x-ratelimit-remaining", "4999")
+                .raw_body(Bytes::from_static(b"not json"))
+                .delay(Duration::from_secs(5))
+                .build()
+                .unwrap(),
+        );
+
+        let endpoint = bill::Congress::builder().congress(117_u8).build().unwrap();
+        let start = tokio::time::Instant::now();
+        let rsp = endpoint.raw_query(&client).await.unwrap();
+
+        assert_eq!(start.elapsed(), Duration::from_secs(5));
+        assert_eq!(rsp.headers()["x-ratelimit-remaining"], "4999");
+        assert_eq!(rsp.body().as_ref(), b"not json");
+        assert_eq!(
+            client.calls()[0].query,
+            vec![("api_key".into(), "KEY".into())]
+        );
+    }
+}
@@ -0,0 +1,574 @@
+//! Links to the public congress.gov website.
+//!
+//! The API describes bills, members and other resources which also have a page on
+//! <https://www.congress.gov>. A [`WebPage`] names such a page and converts to and from its
+//! canonical URL:
+//!
+//! ```rust
+//! use congressdotgov_rs::api::common::BillType;
+//! use congressdotgov_rs::web::{BillTab, WebPage};
+//!
+//! let page = WebPage::bill(117, BillType::Hr, 3076).with_tab(BillTab::Actions);
+//! assert_eq!(
+//!     page.url().as_str(),
+//!     "https://www.congress.gov/bill/117th-congress/house-bill/3076/all-actions",
+//! );
+//!
+//! let parsed: WebPage = page.url().as_str().parse().unwrap();
+//! assert_eq!(parsed, page);
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use thiserror::Error;
+use url::Url;
+
+use crate::api::{amendments::CongressionalAmendmentType, common::BillType};
+
+/// The root of the public congress.gov website.
+pub const WEB_BASE_URL: &str = "https://www.congress.gov/";
+
+/// Errors which may occur when parsing a [`WebPage`].
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum WebPageError {
+    #[error("failed to parse url: {}", source)]
+    UrlParse {
+        #[from]
+        source: url::ParseError,
+    },
+    #[error("not a congress.gov page: {}", url)]
+    NotCongressGov { url: String },
+    #[error("unknown congress.gov page: {}", path)]
+    UnknownPage { path: String },
+}
+
+/// A tab of the page of a bill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BillTab {
+    Text,
+    Actions,
+    Titles,
+    Amendments,
+    Cosponsors,
+    Committees,
+    RelatedBills,
+    Subjects,
+}
+
+impl BillTab {
+    const ALL: [BillTab; 8] = [
+        BillTab::Text,
+        BillTab::Actions,
+        BillTab::Titles,
+        BillTab::Amendments,
+        BillTab::Cosponsors,
+        BillTab::Committees,
+        BillTab::RelatedBills,
+        BillTab::Subjects,
+    ];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            BillTab::Text => "text",
+            BillTab::Actions => "all-actions",
+            BillTab::Titles => "titles",
+            BillTab::Amendments => "amendments",
+            BillTab::Cosponsors => "cosponsors",
+            BillTab::Committees => "committees",
+            BillTab::RelatedBills => "related-bills",
+            BillTab::Subjects => "subjects",
+        }
+    }
+}
+
+/// A page of the congress.gov website.
+///
+/// Members and committees are identified by their bioguide ID and committee code, along with the
+/// name in their URL, e.g. `nancy-pelosi` in `/member/nancy-pelosi/P000197`. The name is part of
+/// the canonical URL, so it is required.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WebPage {
+    /// A bill or resolution, optionally on one of its tabs.
+    Bill {
+        congress: u16,
+        bill_type: BillType,
+        number: u32,
+        tab: Option<BillTab>,
+        /// The version shown on the text tab, e.g. `ih` for the version introduced in the House.
+        text_version: Option<String>,
+    },
+    /// An amendment.
+    Amendment {
+        congress: u16,
+        amendment_type: CongressionalAmendmentType,
+        number: u32,
+    },
+    /// A member of Congress.
+    Member { bioguide_id: String, name: String },
+    /// A nomination, or a part of a nomination which was split.
+    Nomination {
+        congress: u16,
+        number: u32,
+        part: Option<u32>,
+    },
+    /// A treaty document.
+    Treaty {
+        congress: u16,
+        number: u32,
+        suffix: Option<String>,
+    },
+    /// A committee or subcommittee.
+    Committee { code: String, name: String },
+}
+
+impl WebPage {
+    /// The page of a bill.
+    pub fn bill(congress: u16, bill_type: BillType, number: u32) -> Self {
+        WebPage::Bill {
+            congress,
+            bill_type,
+            number,
+            tab: None,
+            text_version: None,
+        }
+    }
+
+    /// The page of an amendment.
+    pub fn amendment(
+        congress: u16,
+        amendment_type: CongressionalAmendmentType,
+        number: u32,
+    ) -> Self {
+        WebPage::Amendment {
+            congress,
+            amendment_type,
+            number,
+        }
+    }
+
+    /// The page of a member, by the name in its URL and bioguide ID, e.g. `nancy-pelosi` and
+    /// `P000197`.
+    pub fn member<N, I>(name: N, bioguide_id: I) -> Self
+    where
+        N: Into<String>,
+        I: Into<String>,
+    {
+        WebPage::Member {
+            bioguide_id: bioguide_id.into(),
+            name: name.into(),
+        }
+    }
+
+    /// The page of a nomination.
+    pub fn nomination(congress: u16, number: u32) -> Self {
+        WebPage::Nomination {
+            congress,
+            number,
+            part: None,
+        }
+    }
+
+    /// The page of a treaty document.
+    pub fn treaty(congress: u16, number: u32) -> Self {
+        WebPage::Treaty {
+            congress,
+            number,
+            suffix: None,
+        }
+    }
+
+    /// The page of a committee, by the name in its URL and committee code, e.g.
+    /// `house-agriculture` and `hsag00`.
+    pub fn committee<N, C>(name: N, code: C) -> Self
+    where
+        N: Into<String>,
+        C: Into<String>,
+    {
+        WebPage::Committee {
+            code: code.into(),
+            name: name.into(),
+        }
+    }
+
+    /// Show a tab of the page of a bill. Other pages have no tabs and are left as they are.
+    pub fn with_tab(mut self, tab: BillTab) -> Self {
+        if let WebPage::Bill {
+            tab: current,
+            text_version,
+            ..
+        } = &mut self
+        {
+            *current = Some(tab);
+            if tab != BillTab::Text {
+                *text_version = None;
+            }
+        }
+        self
+    }
+
+    /// Show a version of the text of a bill, e.g. `ih` for the version introduced in the House.
+    /// Other pages have no text versions and are left as they are.
+    pub fn with_text_version<S>(mut self, version: S) -> Self
+    where
+        S: Into<String>,
+    {
+        if let WebPage::Bill {
+            tab, text_version, ..
+        } = &mut self
+        {
+            *tab = Some(BillTab::Text);
+            *text_version = Some(version.into());
+        }
+        self
+    }
+
+    /// The canonical URL of the page.
+    pub fn url(&self) -> Url {
+        let mut url = Url::parse(WEB_BASE_URL).expect("the base URL is valid");
+        url.set_path(&self.path());
+        url
+    }
+
+    fn path(&self) -> String {
+        match self {
+            WebPage::Bill {
+                congress,
+                bill_type,
+                number,
+                tab,
+                text_version,
+            } => {
+                let mut path = format!(
+                    "bill/{}/{}/{number}",
+                    ordinal(*congress),
+                    bill_type_slug(*bill_type)
+                );
+                if let Some(tab) = tab {
+                    path.push('/');
+                    path.push_str(tab.as_str());
+                }
+                if let Some(version) = text_version {
+                    path.push('/');
+                    path.push_str(version);
+                }
+                path
+            }
+            WebPage::Amendment {
+                congress,
+                amendment_type,
+                number,
+            } => format!(
+                "amendment/{}/{}/{number}",
+                ordinal(*congress),
+                amendment_type_slug(*amendment_type)
+            ),
+            WebPage::Member { bioguide_id, name } => format!("member/{name}/{bioguide_id}"),
+            WebPage::Nomination {
+                congress,
+                number,
+                part,
+            } => match part {
+                Some(part) => format!("nomination/{}/{number}/{part}", ordinal(*congress)),
+                None => format!("nomination/{}/{number}", ordinal(*congress)),
+            },
+            WebPage::Treaty {
+                congress,
+                number,
+                suffix,
+            } => format!(
+                "treaty-document/{}/{number}{}",
+                ordinal(*congress),
+                suffix.as_deref().unwrap_or_default()
+            ),
+            WebPage::Committee { code, name } => format!("committee/{name}/{code}"),
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+        Some(match segments[..] {
+            ["bill", congress, bill_type, number, ref tab @ ..] if tab.len() <= 2 => {
+                let (tab, text_version) = match *tab {
+                    [] => (None, None),
+                    [tab] => (Some(tab), None),
+                    ["text", version] if is_text_version(version) => {
+                        (Some("text"), Some(version.into()))
+                    }
+                    _ => return None,
+                };
+                WebPage::Bill {
+                    congress: parse_ordinal(congress)?,
+                    bill_type: [
+                        BillType::Hr,
+                        BillType::S,
+                        BillType::Hjres,
+                        BillType::Sjres,
+                        BillType::Hconres,
+                        BillType::Sconres,
+                        BillType::Hres,
+                        BillType::Sres,
+                    ]
+                    .into_iter()
+                    .find(|ty| bill_type_slug(*ty) == bill_type)?,
+                    number: number.parse().ok()?,
+                    tab: match tab {
+                        Some(tab) => Some(BillTab::ALL.into_iter().find(|t| t.as_str() == tab)?),
+                        None => None,
+                    },
+                    text_version,
+                }
+            }
+            ["amendment", congress, amendment_type, number] => WebPage::Amendment {
+                congress: parse_ordinal(congress)?,
+                amendment_type: [
+                    CongressionalAmendmentType::Hamdt,
+                    CongressionalAmendmentType::Samdt,
+                    CongressionalAmendmentType::Suamdt,
+                ]
+                .into_iter()
+                .find(|ty| amendment_type_slug(*ty) == amendment_type)?,
+                number: number.parse().ok()?,
+            },
+            ["member", name, bioguide_id] => WebPage::member(name, bioguide_id),
+            ["nomination", congress, number, ref part @ ..] if part.len() <= 1 => {
+                WebPage::Nomination {
+                    congress: parse_ordinal(congress)?,
+                    number: number.parse().ok()?,
+                    part: match part.first() {
+                        Some(part) => Some(part.parse().ok()?),
+                        None => None,
+                    },
+                }
+            }
+            ["treaty-document", congress, number] => {
+                let split = number
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(number.len());
+                let (number, suffix) = number.split_at(split);
+                WebPage::Treaty {
+                    congress: parse_ordinal(congress)?,
+                    number: number.parse().ok()?,
+                    suffix: (!suffix.is_empty()).then(|| suffix.into()),
+                }
+            }
+            ["committee", name, code] => WebPage::committee(name, code),
+            _ => return None,
+        })
+    }
+}
+
+impl fmt::Display for WebPage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.url().fmt(f)
+    }
+}
+
+impl TryFrom<&Url> for WebPage {
+    type Error = WebPageError;
+
+    fn try_from(url: &Url) -> Result<Self, Self::Error> {
+        if !matches!(url.host_str(), Some("www.congress.gov" | "congress.gov")) {
+            return Err(WebPageError::NotCongressGov {
+                url: url.as_str().into(),
+            });
+        }
+
+        WebPage::from_path(url.path()).ok_or_else(|| WebPageError::UnknownPage {
+            path: url.path().into(),
+        })
+    }
+}
+
+impl TryFrom<Url> for WebPage {
+    type Error = WebPageError;
+
+    fn try_from(url: Url) -> Result<Self, Self::Error> {
+        WebPage::try_from(&url)
+    }
+}
+
+impl FromStr for WebPage {
+    type Err = WebPageError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        WebPage::try_from(&Url::parse(s)?)
+    }
+}
+
+fn bill_type_slug(bill_type: BillType) -> &'static str {
+    match bill_type {
+        BillType::Hr => "house-bill",
+        BillType::S => "senate-bill",
+        BillType::Hjres => "house-joint-resolution",
+        BillType::Sjres => "senate-joint-resolution",
+        BillType::Hconres => "house-concurrent-resolution",
+        BillType::Sconres => "senate-concurrent-resolution",
+        BillType::Hres => "house-resolution",
+        BillType::Sres => "senate-resolution",
+    }
+}
+
+fn amendment_type_slug(amendment_type: CongressionalAmendmentType) -> &'static str {
+    match amendment_type {
+        CongressionalAmendmentType::Hamdt => "house-amendment",
+        CongressionalAmendmentType::Samdt => "senate-amendment",
+        CongressionalAmendmentType::Suamdt => "senate-unprinted-amendment",
+    }
+}
+
+/// Whether a segment is a text version code, e.g. `ih` or `enr`.
+fn is_text_version(segment: &str) -> bool {
+    !segment.is_empty()
+        && segment
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+}
+
+/// The congress as it appears in page URLs, e.g. `117th-congress`.
+fn ordinal(congress: u16) -> String {
+    let suffix = match (congress % 10, congress % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{congress}{suffix}-congress")
+}
+
+fn parse_ordinal(segment: &str) -> Option<u16> {
+    let digits = segment.strip_suffix("-congress")?;
+    let congress = digits
+        .trim_end_matches(|c: char| c.is_ascii_alphabetic())
+        .parse()
+        .ok()?;
+    (ordinal(congress) == segment).then_some(congress)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ordinals() {
+        assert_eq!(ordinal(101), "101st-congress");
+        assert_eq!(ordinal(102), "102nd-congress");
+        assert_eq!(ordinal(103), "103rd-congress");
+        assert_eq!(ordinal(111), "111th-congress");
+        assert_eq!(ordinal(112), "112th-congress");
+        assert_eq!(ordinal(118), "118th-congress");
+        assert_eq!(parse_ordinal("113th-congress"), Some(113));
+        assert_eq!(parse_ordinal("113rd-congress"), None);
+        assert_eq!(parse_ordinal("113th"), None);
+    }
+
+    #[test]
+    fn urls() {
+        let cases = [
+            (
+                WebPage::bill(118, BillType::Sjres, 12),
+                "https://www.congress.gov/bill/118th-congress/senate-joint-resolution/12",
+            ),
+            (
+                WebPage::bill(117, BillType::Hr, 3076).with_tab(BillTab::Cosponsors),
+                "https://www.congress.gov/bill/117th-congress/house-bill/3076/cosponsors",
+            ),
+            (
+                WebPage::amendment(117, CongressionalAmendmentType::Samdt, 2137),
+                "https://www.congress.gov/amendment/117th-congress/senate-amendment/2137",
+            ),
+            (
+                WebPage::bill(117, BillType::Hr, 3076).with_text_version("ih"),
+                "https://www.congress.gov/bill/117th-congress/house-bill/3076/text/ih",
+            ),
+            (
+                WebPage::member("nancy-pelosi", "P000197"),
+                "https://www.congress.gov/member/nancy-pelosi/P000197",
+            ),
+            (
+                WebPage::Nomination {
+                    congress: 117,
+                    number: 2467,
+                    part: Some(1),
+                },
+                "https://www.congress.gov/nomination/117th-congress/2467/1",
+            ),
+            (
+                WebPage::Treaty {
+                    congress: 114,
+                    number: 13,
+                    suffix: Some("A".into()),
+                },
+                "https://www.congress.gov/treaty-document/114th-congress/13A",
+            ),
+            (
+                WebPage::committee("house-agriculture", "hsag00"),
+                "https://www.congress.gov/committee/house-agriculture/hsag00",
+            ),
+        ];
+
+        for (page, url) in cases {
+            assert_eq!(page.to_string(), url);
+            assert_eq!(url.parse::<WebPage>().unwrap(), page);
+        }
+    }
+
+    #[test]
+    fn parses_variants() {
+        assert_eq!(
+            "https://congress.gov/bill/117th-congress/house-bill/3076/text?format=txt"
+                .parse::<WebPage>()
+                .unwrap(),
+            WebPage::bill(117, BillType::Hr, 3076).with_tab(BillTab::Text)
+        );
+        assert_eq!(
+            "https://www.congress.gov/bill/117th-congress/house-bill/3076/text/enr?format=txt"
+                .parse::<WebPage>()
+                .unwrap(),
+            WebPage::bill(117, BillType::Hr, 3076).with_text_version("enr")
+        );
+        assert_eq!(
+            WebPage::bill(117, BillType::Hr, 3076)
+                .with_text_version("ih")
+                .with_tab(BillTab::Actions),
+            WebPage::bill(117, BillType::Hr, 3076).with_tab(BillTab::Actions)
+        );
+        assert_eq!(
+            WebPage::nomination(117, 12).with_tab(BillTab::Text),
+            WebPage::nomination(117, 12)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            "https://api.congress.gov/v3/bill".parse::<WebPage>(),
+            Err(WebPageError::NotCongressGov { .. })
+        ));
+
+        let err = "https://www.congress.gov/bill/117th-congress/house-bill/3076/votes"
+            .parse::<WebPage>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown congress.gov page: /bill/117th-congress/house-bill/3076/votes"
+        );
+        assert!(matches!(
+            "https://www.congress.gov/bill/117-congress/house-bill/3076".parse::<WebPage>(),
+            Err(WebPageError::UnknownPage { .. })
+        ));
+        assert!(matches!(
+            "https://www.congress.gov/bill/117th-congress/house-bill/3076/all-actions/ih"
+                .parse::<WebPage>(),
+            Err(WebPageError::UnknownPage { .. })
+        ));
+        assert!(matches!(
+            "https://www.congress.gov/member/P000197".parse::<WebPage>(),
+            Err(WebPageError::UnknownPage { .. })
+        ));
+    }
+}
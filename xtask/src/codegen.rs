@@ -0,0 +1,1010 @@
+//! Generates the endpoints in `src/api` from the OpenAPI document in `spec/`.
+//!
+//! The document is a reconstruction built from the bindings, not the published congress.gov
+//! specification, so `--check` keeps the code in sync with the document but can not detect
+//! changes to the API itself.
+//!
+//! Every operation names the struct it is generated into with an `x-rust-path` extension, e.g.
+//! `bill::bill_number::BillNumber` for `src/api/bill/bill_number.rs`. The generator owns
+//! everything above the `#[cfg(test)]` line of those files; the tests below it are written by
+//! hand and kept as they are.
+//!
+//! Parameters map to Rust types as follows:
+//!
+//! * schemas with an `x-rust-type` extension use that type, and types from the crate are
+//!   imported and rendered with `as_str()` in the path,
+//! * integers use the smallest of `u8`, `u16` and `u32` holding their `maximum`,
+//! * `date-time` strings use `DateTime<Utc>` and other strings `Cow<'a, str>`,
+//! * booleans use `bool`.
+//!
+//! Query parameters are optional. The `format` and `api_key` parameters are set by the client for
+//! every request and are skipped.
+//!
+//! Operations taking `offset` and `limit` implement `Pageable`, unless they set `x-rust-pageable`
+//! to `false` because their items are not a top-level list of the response.
+//!
+//! The `Route` enum in `src/api/route/routes.rs`, which parses URLs back into endpoints, is
+//! generated from the same operations. Its variants are named after the struct, prefixed with the
+//! struct of the root path of the resource unless the name already starts or ends with it, e.g.
+//! `BillActions` for `bill::Actions` but `CommitteeCode` for `committee::CommitteeCode`.
+
+use std::{
+    collections::BTreeSet,
+    error::Error,
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use serde_json::Value;
+
+pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+/// The OpenAPI document, relative to the workspace root.
+pub const SPEC: &str = "spec/congress.openapi.json";
+
+const HEADER: &str = "\
+// This file is generated by `cargo xtask codegen` from `spec/congress.openapi.json`.
+// Edit the spec rather than the code above the tests.
+";
+
+const TESTS: &str = "#[cfg(test)]";
+
+/// The router, relative to the workspace root.
+const ROUTES: &str = "src/api/route/routes.rs";
+
+/// Query parameters added by the client rather than the endpoints.
+const CLIENT_PARAMS: &[&str] = &["format", "api_key"];
+
+/// Regenerate the endpoints, keeping the tests of existing files.
+pub fn write(root: &Path) -> Result<()> {
+    for file in plan(root)? {
+        if file.current.as_deref() != Some(file.expected.as_str()) {
+            fs::write(root.join(&file.path), &file.expected)?;
+            println!("generated {}", file.path.display());
+        }
+        if !file.declared {
+            println!(
+                "note: declare and re-export {} in src/api/{}.rs",
+                file.path.display(),
+                file.module,
+            );
+        }
+    }
+
+    for path in orphans(root)? {
+        println!("note: {} is not in {SPEC} anymore", path.display());
+    }
+
+    Ok(())
+}
+
+/// Fail if the committed endpoints differ from the generated ones.
+pub fn check(root: &Path) -> Result<()> {
+    let mut stale = Vec::new();
+    for file in plan(root)? {
+        match file.current {
+            None => stale.push(format!("{} is missing", file.path.display())),
+            Some(current) if current != file.expected => {
+                stale.push(format!("{} differs", file.path.display()))
+            }
+            Some(_) => {}
+        }
+        if !file.declared {
+            stale.push(format!(
+                "{} is not declared in src/api/{}.rs",
+                file.path.display(),
+                file.module,
+            ));
+        }
+    }
+    for path in orphans(root)? {
+        stale.push(format!("{} is not in {SPEC}", path.display()));
+    }
+
+    if stale.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "the endpoints are out of date with {SPEC}, run `cargo xtask codegen`:\n  {}",
+            stale.join("\n  "),
+        )
+        .into())
+    }
+}
+
+/// A generated file.
+struct File {
+    /// The path relative to the workspace root.
+    path: PathBuf,
+    /// The module declaring the file.
+    module: String,
+    /// Whether the module declares the file.
+    declared: bool,
+    /// The committed contents.
+    current: Option<String>,
+    /// The generated contents, followed by the committed tests.
+    expected: String,
+}
+
+fn plan(root: &Path) -> Result<Vec<File>> {
+    let spec: Value = serde_json::from_str(&fs::read_to_string(root.join(SPEC))?)?;
+    let endpoints = endpoints(&spec)?;
+
+    let mut files = endpoints
+        .iter()
+        .map(|endpoint| {
+            file(
+                root,
+                endpoint.source_path(),
+                &endpoint.module,
+                &endpoint.file,
+                endpoint.render(),
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+    files.push(file(
+        root,
+        ROUTES.into(),
+        "route",
+        "routes",
+        render_routes(&endpoints)?,
+    )?);
+
+    Ok(files)
+}
+
+fn file(root: &Path, path: PathBuf, module: &str, name: &str, source: String) -> Result<File> {
+    let current = fs::read_to_string(root.join(&path)).ok();
+    let declared = fs::read_to_string(root.join(format!("src/api/{module}.rs")))
+        .unwrap_or_default()
+        .lines()
+        .any(|line| line == format!("mod {name};"));
+
+    let mut expected = rustfmt(&source)?;
+    if let Some(tests) = current.as_deref().and_then(tests) {
+        expected.push('\n');
+        expected.push_str(tests);
+    }
+
+    Ok(File {
+        path,
+        module: module.into(),
+        declared,
+        current,
+        expected,
+    })
+}
+
+/// The hand written tests at the end of a file.
+fn tests(source: &str) -> Option<&str> {
+    if source.starts_with(TESTS) {
+        return Some(source);
+    }
+    source
+        .find(&format!("\n{TESTS}\n"))
+        .map(|index| &source[index + 1..])
+}
+
+/// Generated files which no operation in the spec maps to.
+fn orphans(root: &Path) -> Result<Vec<PathBuf>> {
+    let spec: Value = serde_json::from_str(&fs::read_to_string(root.join(SPEC))?)?;
+    let mut expected: BTreeSet<PathBuf> = endpoints(&spec)?
+        .iter()
+        .map(Endpoint::source_path)
+        .collect();
+    expected.insert(ROUTES.into());
+
+    let mut orphans = Vec::new();
+    for module in fs::read_dir(root.join("src/api"))? {
+        let module = module?.path();
+        if !module.is_dir() {
+            continue;
+        }
+        for file in fs::read_dir(&module)? {
+            let file = file?.path();
+            let path = file.strip_prefix(root)?.to_path_buf();
+            if !expected.contains(&path) && fs::read_to_string(&file)?.starts_with(HEADER) {
+                orphans.push(path);
+            }
+        }
+    }
+    orphans.sort();
+
+    Ok(orphans)
+}
+
+fn rustfmt(source: &str) -> Result<String> {
+    let rustfmt = std::env::var("RUSTFMT").unwrap_or_else(|_| "rustfmt".into());
+    let mut child = Command::new(rustfmt)
+        .args(["--edition", "2024"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    child
+        .stdin
+        .take()
+        .ok_or("rustfmt has no stdin")?
+        .write_all(source.as_bytes())?;
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(format!(
+            "rustfmt failed on the generated code:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        )
+        .into());
+    }
+
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+/// An operation of the spec.
+#[derive(Debug)]
+struct Endpoint {
+    module: String,
+    file: String,
+    name: String,
+    path: String,
+    description: Option<String>,
+    params: Vec<Param>,
+    /// Whether the response has a single top-level list, from `x-rust-pageable`.
+    list: bool,
+}
+
+/// A path or query parameter of an operation.
+#[derive(Debug)]
+struct Param {
+    name: String,
+    field: String,
+    query: bool,
+    ty: Type,
+}
+
+/// The Rust type of a parameter.
+#[derive(Debug, PartialEq)]
+enum Type {
+    /// A type displayed as is, such as an integer.
+    Plain(String),
+    /// A type from the crate with an `as_str()` method, by path from the crate root.
+    Crate(String),
+    DateTime,
+    Str,
+}
+
+impl Type {
+    fn from_schema(schema: &Value) -> Result<Self> {
+        if let Some(ty) = schema.get("x-rust-type").and_then(Value::as_str) {
+            return Ok(match ty.strip_prefix("crate::") {
+                Some(path) => Type::Crate(path.into()),
+                None => Type::Plain(ty.into()),
+            });
+        }
+
+        let ty = schema.get("type").and_then(Value::as_str);
+        let format = schema.get("format").and_then(Value::as_str);
+        Ok(match (ty, format) {
+            (Some("integer"), _) => {
+                let ty = match schema.get("maximum").and_then(Value::as_u64) {
+                    Some(max) if max <= u8::MAX.into() => "u8",
+                    Some(max) if max <= u16::MAX.into() => "u16",
+                    _ => "u32",
+                };
+                Type::Plain(ty.into())
+            }
+            (Some("boolean"), _) => Type::Plain("bool".into()),
+            (Some("string"), Some("date-time")) => Type::DateTime,
+            (Some("string"), _) => Type::Str,
+            _ => return Err(format!("unsupported schema {schema}").into()),
+        })
+    }
+
+    fn rust(&self) -> &str {
+        match self {
+            Type::Plain(ty) => ty,
+            Type::Crate(path) => path.rsplit("::").next().unwrap_or(path),
+            Type::DateTime => "DateTime<Utc>",
+            Type::Str => "Cow<'a, str>",
+        }
+    }
+}
+
+impl Type {
+    /// The type as named in the router, where values are owned.
+    fn owned(&self) -> String {
+        match self {
+            Type::Crate(path) => format!("crate::{path}"),
+            Type::Str => "Cow<'static, str>".into(),
+            ty => ty.rust().into(),
+        }
+    }
+}
+
+fn endpoints(spec: &Value) -> Result<Vec<Endpoint>> {
+    let paths = spec
+        .get("paths")
+        .and_then(Value::as_object)
+        .ok_or("the spec has no paths")?;
+
+    let mut endpoints = Vec::new();
+    for (path, item) in paths {
+        let item = item
+            .as_object()
+            .ok_or_else(|| format!("invalid path {path}"))?;
+        for (method, operation) in item {
+            match method.as_str() {
+                "get" => endpoints.push(Endpoint::from_operation(spec, path, item, operation)?),
+                "summary" | "description" | "parameters" | "servers" => {}
+                _ => return Err(format!("unsupported method {method} for {path}").into()),
+            }
+        }
+    }
+
+    Ok(endpoints)
+}
+
+impl Endpoint {
+    fn from_operation(
+        spec: &Value,
+        path: &str,
+        item: &serde_json::Map<String, Value>,
+        operation: &Value,
+    ) -> Result<Self> {
+        let rust_path = operation
+            .get("x-rust-path")
+            .and_then(Value::as_str)
+            .ok_or_else(|| format!("GET {path} has no x-rust-path"))?;
+        let [module, file, name] = rust_path.split("::").collect::<Vec<_>>()[..] else {
+            return Err(format!("x-rust-path of GET {path} is not module::file::Struct").into());
+        };
+
+        let params = item
+            .get("parameters")
+            .into_iter()
+            .chain(operation.get("parameters"))
+            .filter_map(Value::as_array)
+            .flatten()
+            .map(|param| resolve(spec, param))
+            .filter(|param| {
+                !param
+                    .as_ref()
+                    .is_ok_and(|param| CLIENT_PARAMS.contains(&str_field(param, "name")))
+            })
+            .map(|param| Param::new(spec, param?))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Path parameters come first, in the order of the path.
+        let mut ordered = Vec::with_capacity(params.len());
+        for segment in path.split('/').filter_map(path_param) {
+            let param = params
+                .iter()
+                .position(|param| !param.query && param.name == segment)
+                .ok_or_else(|| format!("GET {path} does not describe {{{segment}}}"))?;
+            ordered.push(param);
+        }
+        if ordered.len() != params.iter().filter(|param| !param.query).count() {
+            return Err(format!("GET {path} describes path parameters not in the path").into());
+        }
+        let mut params: Vec<Option<Param>> = params.into_iter().map(Some).collect();
+        let mut sorted: Vec<Param> = ordered
+            .into_iter()
+            .filter_map(|index| params[index].take())
+            .collect();
+        sorted.extend(params.into_iter().flatten());
+
+        Ok(Endpoint {
+            module: module.into(),
+            file: file.into(),
+            name: name.into(),
+            path: path.trim_start_matches('/').into(),
+            description: operation
+                .get("description")
+                .and_then(Value::as_str)
+                .map(Into::into),
+            params: sorted,
+            list: operation
+                .get("x-rust-pageable")
+                .and_then(Value::as_bool)
+                .unwrap_or(true),
+        })
+    }
+
+    fn source_path(&self) -> PathBuf {
+        PathBuf::from(format!("src/api/{}/{}.rs", self.module, self.file))
+    }
+
+    fn query(&self) -> impl Iterator<Item = &Param> {
+        self.params.iter().filter(|param| param.query)
+    }
+
+    fn has_query(&self, name: &str) -> bool {
+        self.query().any(|param| param.name == name)
+    }
+
+    fn pageable(&self) -> bool {
+        self.list && self.has_query("offset") && self.has_query("limit")
+    }
+
+    fn borrowed(&self) -> bool {
+        self.params.iter().any(|param| param.ty == Type::Str)
+    }
+
+    /// The path with parameters written as `:name`.
+    fn doc_path(&self) -> String {
+        self.path
+            .split('/')
+            .map(|segment| match path_param(segment) {
+                Some(segment) => format!(":{segment}"),
+                None => segment.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// The path parameter of each segment of the path, or `None` for literal segments.
+    fn segments(&self) -> impl Iterator<Item = (&str, Option<&Param>)> {
+        self.path.split('/').map(|segment| {
+            let param = path_param(segment).map(|segment| {
+                self.params
+                    .iter()
+                    .find(|param| !param.query && param.name == segment)
+                    .expect("path parameters are checked when parsing")
+            });
+            (segment, param)
+        })
+    }
+
+    fn render(&self) -> String {
+        let name = &self.name;
+        let (lifetime, anonymous) = if self.borrowed() {
+            ("<'a>", "<'_>")
+        } else {
+            ("", "")
+        };
+
+        let mut imports: BTreeSet<String> = [
+            "api::endpoint::Endpoint".to_string(),
+            "api::params::QueryParams".to_string(),
+        ]
+        .into();
+        if self.pageable() {
+            imports.insert("api::paged::Pageable".into());
+        }
+        for param in &self.params {
+            if let Type::Crate(path) = &param.ty
+                && param.ty.rust() != name
+            {
+                imports.insert(path.clone());
+            }
+        }
+
+        let mut out = format!("{HEADER}\n");
+        if self.params.iter().any(|param| param.ty == Type::DateTime) {
+            out.push_str("use chrono::{DateTime, Utc};\n");
+        }
+        out.push_str("use derive_builder::Builder;\nuse http::Method;\nuse std::borrow::Cow;\n\n");
+        out.push_str(&format!(
+            "use crate::{{{}}};\n\n",
+            imports.into_iter().collect::<Vec<_>>().join(", ")
+        ));
+
+        out.push_str(&format!(
+            "/// Represents the /{} endpoint.\n",
+            self.doc_path()
+        ));
+        if let Some(description) = &self.description {
+            out.push_str("///\n");
+            for line in description.lines() {
+                out.push_str(&format!("/// {line}\n").replace("/// \n", "///\n"));
+            }
+        }
+
+        let derive = if self.borrowed() {
+            "Debug, Clone, Builder"
+        } else {
+            "Debug, Clone, Copy, Builder"
+        };
+        out.push_str(&format!(
+            "#[derive({derive})]\n#[builder(setter(strip_option))]\npub struct {name}{lifetime} {{\n"
+        ));
+        for param in &self.params {
+            // A type named like the endpoint is not imported and needs its full path.
+            let ty = match &param.ty {
+                Type::Crate(path) if param.ty.rust() == name => format!("crate::{path}"),
+                ty => ty.rust().to_string(),
+            };
+            let (attr, ty) = if param.query {
+                ("default", format!("Option<{ty}>"))
+            } else {
+                ("setter(into)", ty)
+            };
+            out.push_str(&format!("#[builder({attr})]\n{}: {ty},\n", param.field));
+        }
+        out.push_str("}\n\n");
+
+        let fields = self
+            .params
+            .iter()
+            .map(|param| match param.ty {
+                Type::Str => format!("{0}: Some(self.{0}.clone()),\n", param.field),
+                _ => format!("{0}: Some(self.{0}),\n", param.field),
+            })
+            .collect::<String>();
+        out.push_str(&format!(
+            "impl{lifetime} {name}{lifetime} {{\n\
+             pub fn builder() -> {name}Builder{lifetime} {{\n\
+             {name}Builder::default()\n\
+             }}\n\n\
+             /// A builder with the parameters of this endpoint, e.g. to change some of them.\n\
+             pub fn to_builder(&self) -> {name}Builder{lifetime} {{\n\
+             {name}Builder {{\n\
+             {fields}\
+             }}\n\
+             }}\n\
+             }}\n\n"
+        ));
+
+        let endpoint = if self.params.iter().any(|param| !param.query) {
+            let mut template = String::new();
+            let mut args = Vec::new();
+            for (index, (segment, param)) in self.segments().enumerate() {
+                if index > 0 {
+                    template.push('/');
+                }
+                match param {
+                    Some(param) => {
+                        template.push_str("{}");
+                        args.push(match param.ty {
+                            Type::Crate(_) => format!("self.{}.as_str()", param.field),
+                            _ => format!("self.{}", param.field),
+                        });
+                    }
+                    None => template.push_str(segment),
+                }
+            }
+            format!("format!(\"{template}\", {}).into()", args.join(", "))
+        } else {
+            format!("\"{}\".into()", self.path)
+        };
+
+        let parameters = if self.query().next().is_some() {
+            let mut parameters = String::from("let mut params = QueryParams::default();\n\n");
+            for param in self.query() {
+                parameters.push_str(&format!(
+                    "params.push_opt(\"{}\", self.{});\n",
+                    param.name, param.field
+                ));
+            }
+            parameters.push_str("\nparams");
+            parameters
+        } else {
+            "QueryParams::default()".into()
+        };
+
+        out.push_str(&format!(
+            "impl Endpoint for {name}{anonymous} {{\n\
+             fn method(&self) -> Method {{\n\
+             Method::GET\n\
+             }}\n\n\
+             fn endpoint(&self) -> Cow<'static, str> {{\n\
+             {endpoint}\n\
+             }}\n\n\
+             fn parameters(&self) -> QueryParams<'_> {{\n\
+             {parameters}\n\
+             }}\n\
+             }}\n"
+        ));
+
+        if self.pageable() {
+            out.push_str(&format!("\nimpl Pageable for {name}{anonymous} {{}}\n"));
+        }
+
+        out
+    }
+}
+
+impl Param {
+    fn new(spec: &Value, param: &Value) -> Result<Self> {
+        let name = str_field(param, "name");
+        let query = match str_field(param, "in") {
+            "path" => false,
+            "query" => true,
+            other => return Err(format!("unsupported parameter location {other} of {name}").into()),
+        };
+        let schema = resolve(spec, param.get("schema").unwrap_or(&Value::Null))?;
+
+        Ok(Param {
+            name: name.into(),
+            field: param
+                .get("x-rust-name")
+                .and_then(Value::as_str)
+                .map_or_else(|| snake_case(name), Into::into),
+            query,
+            ty: Type::from_schema(schema)?,
+        })
+    }
+}
+
+/// Render the `Route` enum with a variant for every endpoint.
+fn render_routes(endpoints: &[Endpoint]) -> Result<String> {
+    let variants = variants(endpoints)?;
+
+    // Literal segments are matched before parameters, and parameters which must parse as a
+    // specific type before plain strings, so that e.g. `committee/house` is not taken for a
+    // congress and `member/congress/118` is not taken for a state.
+    let mut order: Vec<usize> = (0..endpoints.len()).collect();
+    order.sort_by_key(|&index| {
+        endpoints[index]
+            .segments()
+            .map(|(_, param)| match param.map(|param| &param.ty) {
+                None => 0,
+                Some(Type::Str) => 2,
+                Some(_) => 1,
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let modules: BTreeSet<&str> = endpoints
+        .iter()
+        .map(|endpoint| endpoint.module.as_str())
+        .collect();
+    let mut out = format!("{HEADER}\n");
+    if endpoints
+        .iter()
+        .flat_map(|endpoint| &endpoint.params)
+        .any(|param| param.ty == Type::DateTime)
+    {
+        out.push_str("use chrono::{DateTime, Utc};\n");
+    }
+    out.push_str("use std::borrow::Cow;\n\n");
+    out.push_str(&format!(
+        "use crate::api::{{endpoint::Endpoint, route::{{Params, RouteError, segment}}}};\n\
+         use crate::api::{{{}}};\n\n",
+        modules.into_iter().collect::<Vec<_>>().join(", ")
+    ));
+
+    out.push_str(
+        "/// An endpoint of the API, as parsed from its URL.\n\
+         #[derive(Debug, Clone)]\n\
+         #[non_exhaustive]\n\
+         pub enum Route {\n",
+    );
+    for (endpoint, variant) in endpoints.iter().zip(&variants) {
+        let lifetime = if endpoint.borrowed() { "<'static>" } else { "" };
+        out.push_str(&format!(
+            "/// The /{} endpoint.\n{variant}({}::{}{lifetime}),\n",
+            endpoint.doc_path(),
+            endpoint.module,
+            endpoint.name,
+        ));
+    }
+    out.push_str("}\n\n");
+
+    out.push_str(
+        "impl Route {\n\
+         /// Parse the endpoint for a path relative to the API root, taking its query \
+         parameters.\n\
+         pub(crate) fn from_segments(segments: &[&str], params: &mut Params) -> \
+         Result<Self, RouteError> {\n",
+    );
+    for index in order {
+        let endpoint = &endpoints[index];
+        let pattern = endpoint
+            .segments()
+            .map(|(segment, param)| match param {
+                Some(param) => param.field.clone(),
+                None => format!("{segment:?}"),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        out.push_str(&format!("if let [{pattern}] = segments"));
+        for param in endpoint.params.iter().filter(|param| !param.query) {
+            out.push_str(&format!(
+                "\n&& let Some({0}) = segment::<{1}>({0})",
+                param.field,
+                param.ty.owned(),
+            ));
+        }
+
+        let mutable = if endpoint.params.is_empty() {
+            ""
+        } else {
+            "mut "
+        };
+        out.push_str(&format!(
+            " {{\nlet {mutable}builder = {}::{}::builder();\n",
+            endpoint.module, endpoint.name,
+        ));
+        let path = endpoint
+            .params
+            .iter()
+            .filter(|param| !param.query)
+            .map(|param| format!(".{0}({0})", param.field))
+            .collect::<String>();
+        if !path.is_empty() {
+            out.push_str(&format!("builder{path};\n"));
+        }
+        for param in endpoint.query() {
+            out.push_str(&format!(
+                "if let Some(value) = params.take::<{}>(\"{}\")? {{\nbuilder.{}(value);\n}}\n",
+                param.ty.owned(),
+                param.name,
+                param.field,
+            ));
+        }
+        out.push_str(&format!(
+            "return Ok(Route::{}(builder.build().expect(\"the path parameters are set\")));\n}}\n\n",
+            variants[index],
+        ));
+    }
+    out.push_str(
+        "Err(RouteError::UnknownPath {\n\
+         path: segments.join(\"/\"),\n\
+         })\n\
+         }\n\n",
+    );
+
+    let pageable: Vec<String> = endpoints
+        .iter()
+        .zip(&variants)
+        .filter(|(endpoint, _)| endpoint.pageable())
+        .map(|(_, variant)| format!("Route::{variant}(_)"))
+        .collect();
+    out.push_str(
+        "/// Whether the endpoint is [`Pageable`](crate::api::Pageable), i.e. responds with a \
+         list of items.\n\
+         pub fn is_pageable(&self) -> bool {\n",
+    );
+    if pageable.is_empty() {
+        out.push_str("false\n}\n\n");
+    } else {
+        out.push_str(&format!("matches!(self, {})\n}}\n\n", pageable.join(" | ")));
+    }
+
+    out.push_str("pub(crate) fn as_endpoint(&self) -> &dyn Endpoint {\nmatch self {\n");
+    for variant in &variants {
+        out.push_str(&format!("Route::{variant}(endpoint) => endpoint,\n"));
+    }
+    out.push_str("}\n}\n}\n");
+
+    Ok(out)
+}
+
+/// The name of the `Route` variant of each endpoint.
+fn variants(endpoints: &[Endpoint]) -> Result<Vec<String>> {
+    let mut variants = Vec::with_capacity(endpoints.len());
+    let mut seen = BTreeSet::new();
+    for endpoint in endpoints {
+        // The resource is named after the struct of its root path, e.g. `CrsReport` for
+        // `/crsreport`. Resources without one, such as `/law`, use the module name.
+        let resource = endpoints
+            .iter()
+            .find(|root| root.module == endpoint.module && !root.path.contains('/'))
+            .map_or_else(|| camel_case(&endpoint.module), |root| root.name.clone());
+
+        let name = &endpoint.name;
+        let variant = match name.strip_prefix(resource.as_str()) {
+            Some(rest) if rest.is_empty() || rest.starts_with(char::is_uppercase) => name.clone(),
+            _ if name.ends_with(resource.as_str()) => name.clone(),
+            _ => format!("{resource}{name}"),
+        };
+        if !seen.insert(variant.clone()) {
+            return Err(format!("several endpoints map to the route {variant}").into());
+        }
+        variants.push(variant);
+    }
+
+    Ok(variants)
+}
+
+/// The name of a path parameter, for segments such as `{congress}`.
+fn path_param(segment: &str) -> Option<&str> {
+    segment
+        .strip_prefix('{')
+        .and_then(|segment| segment.strip_suffix('}'))
+}
+
+/// Follow a local `$ref`.
+fn resolve<'a>(spec: &'a Value, value: &'a Value) -> Result<&'a Value> {
+    match value.get("$ref").and_then(Value::as_str) {
+        Some(reference) => {
+            let target = reference
+                .strip_prefix('#')
+                .and_then(|pointer| spec.pointer(pointer))
+                .ok_or_else(|| format!("unresolved reference {reference}"))?;
+            resolve(spec, target)
+        }
+        None => Ok(value),
+    }
+}
+
+fn str_field<'a>(value: &'a Value, field: &str) -> &'a str {
+    value.get(field).and_then(Value::as_str).unwrap_or_default()
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
+
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn committed_endpoints_are_up_to_date() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+
+        if let Err(err) = check(root) {
+            panic!("{err}");
+        }
+    }
+
+    #[test]
+    fn field_names() {
+        assert_eq!(snake_case("billType"), "bill_type");
+        assert_eq!(snake_case("fromDateTime"), "from_date_time");
+        assert_eq!(snake_case("congress"), "congress");
+        assert_eq!(camel_case("law"), "Law");
+        assert_eq!(camel_case("house_communication"), "HouseCommunication");
+    }
+
+    #[test]
+    fn types() {
+        let ty = |schema| Type::from_schema(&schema).unwrap();
+
+        assert_eq!(
+            ty(json!({ "type": "integer", "maximum": 250 })).rust(),
+            "u8"
+        );
+        assert_eq!(
+            ty(json!({ "type": "integer", "maximum": 9999 })).rust(),
+            "u16"
+        );
+        assert_eq!(ty(json!({ "type": "integer" })).rust(), "u32");
+        assert_eq!(
+            ty(json!({ "type": "string", "format": "date-time" })).rust(),
+            "DateTime<Utc>"
+        );
+        assert_eq!(ty(json!({ "type": "string" })).rust(), "Cow<'a, str>");
+        assert_eq!(
+            ty(json!({ "type": "string", "x-rust-type": "crate::api::common::BillType" })),
+            Type::Crate("api::common::BillType".into())
+        );
+    }
+
+    #[test]
+    fn route_variants() {
+        let endpoint = |path: &str, rust_path: &str| {
+            let [module, file, name] = rust_path.split("::").collect::<Vec<_>>()[..] else {
+                unreachable!();
+            };
+            Endpoint {
+                module: module.into(),
+                file: file.into(),
+                name: name.into(),
+                path: path.into(),
+                description: None,
+                params: Vec::new(),
+                list: true,
+            }
+        };
+        let endpoints = [
+            endpoint("committee", "committee::committee::Committee"),
+            endpoint("committee/{chamber}", "committee::chamber::Chamber"),
+            endpoint(
+                "committee/{chamber}/{committeeCode}",
+                "committee::committee_code::CommitteeCode",
+            ),
+            endpoint(
+                "congress/current",
+                "congress::current_congress::CurrentCongress",
+            ),
+            endpoint("law/{congress}", "law::congress::Congress"),
+        ];
+
+        assert_eq!(
+            variants(&endpoints).unwrap(),
+            [
+                "Committee",
+                "CommitteeChamber",
+                "CommitteeCode",
+                "CurrentCongress",
+                "LawCongress",
+            ]
+        );
+        assert!(
+            variants(&[
+                endpoint("law/{congress}", "law::congress::Congress"),
+                endpoint("law/{congress}/{lawType}", "law::law_type::Congress"),
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn renders_endpoint() {
+        let spec = json!({
+            "paths": {
+                "/bill/{congress}/{billType}": {
+                    "get": {
+                        "x-rust-path": "bill::bill_type::BillType",
+                        "parameters": [
+                            { "name": "billType", "in": "path", "schema": { "$ref": "#/components/schemas/BillType" } },
+                            { "name": "congress", "in": "path", "schema": { "type": "integer", "x-rust-type": "u16" } },
+                            { "name": "offset", "in": "query", "schema": { "type": "integer" } },
+                            { "name": "limit", "in": "query", "schema": { "type": "integer", "maximum": 250 } },
+                            { "name": "format", "in": "query", "schema": { "type": "string" } },
+                        ],
+                    },
+                },
+            },
+            "components": {
+                "schemas": {
+                    "BillType": { "type": "string", "x-rust-type": "crate::api::common::BillType" },
+                },
+            },
+        });
+
+        let endpoints = endpoints(&spec).unwrap();
+        let source = endpoints[0].render();
+
+        assert_eq!(
+            endpoints[0].source_path(),
+            Path::new("src/api/bill/bill_type.rs")
+        );
+        assert!(source.contains(
+            "congress: u16,\n#[builder(setter(into))]\nbill_type: crate::api::common::BillType,"
+        ));
+        assert!(source.contains("format!(\"bill/{}/{}\", self.congress, self.bill_type.as_str())"));
+        assert!(source.contains("params.push_opt(\"limit\", self.limit);"));
+        assert!(!source.contains("\"format\""));
+        assert!(source.contains("impl Pageable for BillType {}"));
+    }
+
+    #[test]
+    fn renders_unpageable_endpoint() {
+        let spec = json!({
+            "paths": {
+                "/congressional-record": {
+                    "get": {
+                        "x-rust-path": "congressional_record::congressional_record::CongressionalRecord",
+                        "x-rust-pageable": false,
+                        "parameters": [
+                            { "name": "offset", "in": "query", "schema": { "type": "integer" } },
+                            { "name": "limit", "in": "query", "schema": { "type": "integer", "maximum": 250 } },
+                        ],
+                    },
+                },
+            },
+        });
+
+        let endpoints = endpoints(&spec).unwrap();
+        let source = endpoints[0].render();
+
+        assert!(source.contains("params.push_opt(\"offset\", self.offset);"));
+        assert!(!source.contains("Pageable"));
+    }
+}
@@ -0,0 +1,42 @@
+//! Development tasks for congressdotgov_rs, run with `cargo xtask <task>`.
+
+use std::{env, path::PathBuf, process::ExitCode};
+
+mod codegen;
+
+const USAGE: &str = "\
+Usage: cargo xtask <task>
+
+Tasks:
+    codegen          Regenerate the endpoints in src/api from spec/congress.openapi.json
+    codegen --check  Fail if the committed endpoints differ from the generated ones";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let result = match args.as_slice() {
+        ["codegen"] => codegen::write(&root()),
+        ["codegen", "--check"] => codegen::check(&root()),
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The root of the workspace.
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask lives in the workspace")
+        .to_path_buf()
+}